@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating: the state after `start` steps is seen again
+/// every `length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps leading to the same state as `steps` steps
+    pub fn equivalent_step(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+
+    /// Extrapolates the state after `steps` steps by only simulating up to the equivalent step
+    pub fn state_after<S>(&self, initial: S, step: impl Fn(&S) -> S, steps: usize) -> S {
+        (0..self.equivalent_step(steps)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Brent's algorithm, keeping only two states in memory
+pub fn detect_cycle<S: Clone + PartialEq>(initial: S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| step(&state));
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Floyd's tortoise and hare, which steps more often than Brent's but never compares far apart
+/// states
+pub fn detect_cycle_floyd<S: Clone + PartialEq>(initial: S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Remembers every state, stepping each one only once, for when a step is much more expensive
+/// than hashing a state. Also returns the states seen, so any step can be looked up afterwards.
+pub fn detect_cycle_hashed<S: Clone + Hash + Eq>(
+    initial: S,
+    step: impl Fn(&S) -> S,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = history.len() - start;
+            return (Cycle { start, length }, history);
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ...
+    fn collatz(n: &u64) -> u64 {
+        if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        }
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        let expected = Cycle {
+            start: 5,
+            length: 3,
+        };
+        assert_eq!(detect_cycle(3, collatz), expected);
+        assert_eq!(detect_cycle_floyd(3, collatz), expected);
+        let (cycle, history) = detect_cycle_hashed(3, collatz);
+        assert_eq!(cycle, expected);
+        assert_eq!(history, vec![3, 10, 5, 16, 8, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_cycle_from_the_start() {
+        let expected = Cycle {
+            start: 0,
+            length: 4,
+        };
+        assert_eq!(detect_cycle(0, |n| (n + 1) % 4), expected);
+        assert_eq!(detect_cycle_floyd(0, |n| (n + 1) % 4), expected);
+        assert_eq!(detect_cycle_hashed(0, |n| (n + 1) % 4).0, expected);
+    }
+
+    #[test]
+    fn test_state_after() {
+        let cycle = detect_cycle(3, collatz);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 7);
+        assert_eq!(cycle.state_after(3, collatz, 1_000_000_000), 1);
+        assert_eq!(cycle.state_after(3, collatz, 2), 5);
+    }
+}
@@ -1,11 +1,7 @@
-use parser::{Direction, MultiLineParser, Nearable, Point};
+use parser::{Direction, MultiLineParser, Point};
 use std::collections::HashMap;
 
-pub fn dijkstra(
-    source: Point,
-    sink: Point,
-    map: &mut MultiLineParser,
-) -> (Option<usize>, Vec<Point>) {
+pub fn dijkstra(source: Point, sink: Point, map: &MultiLineParser) -> (Option<usize>, Vec<Point>) {
     let mut seen = HashMap::new();
     let mut prev: HashMap<Point, Vec<Point>> = HashMap::new();
     let mut costs: HashMap<Point, usize> = HashMap::new();
@@ -16,11 +12,10 @@ pub fn dijkstra(
         let current = get_lowest_cost_from_frontier(&frontier, &costs);
         remove_from_frontier(&mut frontier, &current);
         seen.insert(current, true);
-        map.go_to(current);
         if current == sink {
             break;
         }
-        let adjs: Vec<Point> = get_neighbors(map);
+        let adjs: Vec<Point> = get_neighbors(map, current);
         for adj in adjs.iter() {
             if seen.contains_key(adj) {
                 continue;
@@ -72,14 +67,9 @@ fn remove_from_frontier(frontier: &mut Vec<Point>, curr: &Point) {
     frontier.remove(position);
 }
 
-fn get_neighbors(map: &MultiLineParser) -> Vec<Point> {
-    let mut neighbors = vec![];
-    for dir in Direction::VALUES_4 {
-        match map.peek_next_with_direction(&dir) {
-            Some('#') => (),
-            Some(_) => neighbors.push(map.point().with_direction(&dir)),
-            None => (),
-        }
-    }
-    neighbors
+fn get_neighbors(map: &MultiLineParser, point: Point) -> Vec<Point> {
+    map.neighbors(point, &Direction::VALUES_4)
+        .filter(|&(_, c)| c != '#')
+        .map(|(point, _)| point)
+        .collect()
 }
@@ -1,3 +1,5 @@
+mod cycle;
 mod dijkstra;
 
+pub use cycle::{detect_cycle, detect_cycle_floyd, detect_cycle_hashed, Cycle};
 pub use dijkstra::dijkstra;
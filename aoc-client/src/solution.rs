@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SolutionPart {
     pub year: u32,
     pub day: u8,
@@ -0,0 +1,248 @@
+//! A cursor over the bytes of a borrowed string, for inputs large enough that building the
+//! `Vec<char>` of a [`Parser`](crate::Parser) shows up in benchmarks.
+//!
+//! The cursor API mirrors `Parser`, but works on bytes: positions are byte offsets and targets are
+//! ASCII bytes. Nothing is allocated while parsing.
+//!
+//! ```
+//! use parser::ByteParser;
+//!
+//! let mut parser = ByteParser::new("190: 10 19\n");
+//! assert_eq!(parser.match_number_up_to(b':'), Some(190));
+//! assert_eq!(parser.split_to_numbers_whitespace().collect::<Vec<_>>(), vec![10, 19]);
+//! ```
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteParser<'a> {
+    bytes: &'a [u8],
+    cursor: usize,
+}
+
+impl Display for ByteParser<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.bytes))
+    }
+}
+
+impl<'a> ByteParser<'a> {
+    /// Trims the input like `Parser::new` does
+    pub fn new(str: &'a str) -> Self {
+        Self::from_bytes(str.trim().as_bytes())
+    }
+
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self { bytes, cursor: 0 }
+    }
+
+    /// One parser per non-empty line, like the lines of a `MultiLineParser`
+    pub fn lines(str: &'a str) -> impl Iterator<Item = ByteParser<'a>> {
+        str.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| Self::from_bytes(line.as_bytes()))
+    }
+
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The bytes from the cursor on
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.cursor..]
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn go_to(&mut self, to: usize) -> &mut Self {
+        self.cursor = to.min(self.bytes.len());
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.cursor == self.bytes.len()
+    }
+
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn peek(&self) -> Option<&'a u8> {
+        self.bytes.get(self.cursor)
+    }
+
+    pub fn peek_at(&self, num: i32) -> Option<&'a u8> {
+        let index = self.cursor as i64 + num as i64;
+        if index < 0 {
+            return None;
+        }
+        self.bytes.get(index as usize)
+    }
+
+    pub fn pop(&mut self) -> Option<&'a u8> {
+        let value = self.bytes.get(self.cursor);
+        if !self.is_done() {
+            self.cursor += 1;
+        }
+        value
+    }
+
+    pub fn advance(&mut self, num: usize) -> usize {
+        let available = self.bytes.len() - self.cursor;
+        self.cursor += num.min(available);
+        num.saturating_sub(available)
+    }
+
+    /// Moves the cursor onto the last byte of the next occurrence of `target`, or to the end
+    pub fn advance_to(&mut self, target: &str) -> bool {
+        let target = target.as_bytes();
+        match self
+            .remaining()
+            .windows(target.len())
+            .position(|window| window == target)
+        {
+            Some(position) => {
+                self.cursor += position + target.len() - 1;
+                true
+            }
+            None => {
+                self.cursor = self.bytes.len();
+                false
+            }
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) -> &mut Self {
+        while self.peek().is_some_and(u8::is_ascii_whitespace) {
+            self.cursor += 1;
+        }
+        self
+    }
+
+    pub fn match_number<T: FromStr>(&mut self) -> Option<T> {
+        let start = self.cursor;
+        self.skip_digits();
+        self.parse_from(start)
+    }
+
+    /// Matches a number with an optional leading `-` or `+`, leaving the cursor in place if no
+    /// digits follow the sign
+    pub fn match_signed_number<T: FromStr>(&mut self) -> Option<T> {
+        let start = self.cursor;
+        if matches!(self.peek(), Some(b'-' | b'+')) {
+            self.cursor += 1;
+        }
+        if !self.peek().is_some_and(u8::is_ascii_digit) {
+            self.cursor = start;
+            return None;
+        }
+        self.skip_digits();
+        self.parse_from(start)
+    }
+
+    pub fn match_number_up_to(&mut self, target: u8) -> Option<i64> {
+        let number = self.match_number();
+        if self.peek() == Some(&target) {
+            self.cursor += 1;
+            return number;
+        }
+        None
+    }
+
+    pub fn split_to_numbers(&self, delimiter: &'a str) -> impl Iterator<Item = i64> + 'a {
+        self.remaining_str()
+            .split(delimiter)
+            .map(|n| n.parse().unwrap())
+    }
+
+    pub fn split_to_numbers_whitespace(&self) -> impl Iterator<Item = i64> + 'a {
+        self.remaining_str()
+            .split_ascii_whitespace()
+            .map(|n| n.parse().unwrap())
+    }
+
+    pub fn split_to_strings(&self, delimiter: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.remaining_str().split(delimiter)
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(u8::is_ascii_digit) {
+            self.cursor += 1;
+        }
+    }
+
+    fn remaining_str(&self) -> &'a str {
+        std::str::from_utf8(self.remaining()).expect("cursor is not on a character boundary")
+    }
+
+    /// Parses the bytes consumed since `start`, moving the cursor back to `start` on failure
+    fn parse_from<T: FromStr>(&mut self, start: usize) -> Option<T> {
+        // only ASCII signs and digits were consumed, so the slice is valid UTF-8
+        let number = std::str::from_utf8(&self.bytes[start..self.cursor])
+            .ok()
+            .and_then(|n| n.parse().ok());
+        if number.is_none() {
+            self.cursor = start;
+        }
+        number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_to() {
+        let mut parser = ByteParser::new("Button A: X+94, Y+34");
+        assert!(parser.advance_to("X+"));
+        assert_eq!(parser.peek(), Some(&b'+'));
+        parser.advance(1);
+        assert_eq!(parser.match_number_up_to(b','), Some(94));
+        assert!(!parser.advance_to("Z"));
+        assert!(parser.is_done());
+    }
+
+    #[test]
+    fn test_match_signed_number() {
+        let mut parser = ByteParser::new("p=-12,+3 -x");
+        parser.advance(2);
+        assert_eq!(parser.match_signed_number::<i64>(), Some(-12));
+        parser.advance(1);
+        assert_eq!(parser.match_signed_number::<u32>(), Some(3));
+        parser.advance(1);
+        assert_eq!(parser.match_signed_number::<i64>(), None);
+        assert_eq!(parser.peek_at(0), Some(&b'-'));
+        assert_eq!(parser.peek_at(-10), None);
+        let mut parser = ByteParser::new("-300");
+        assert_eq!(parser.match_signed_number::<i8>(), None);
+        assert_eq!(parser.cursor(), 0);
+        parser.advance(1);
+        assert_eq!(parser.match_number::<u8>(), None);
+        assert_eq!(parser.cursor(), 1);
+    }
+
+    #[test]
+    fn test_split_to_numbers() {
+        let parser = ByteParser::new("3,16,5");
+        assert_eq!(
+            parser.split_to_numbers(",").collect::<Vec<_>>(),
+            vec![3, 16, 5]
+        );
+        let lines = ByteParser::lines("7 6 4\n\n 1 2\n")
+            .map(|line| line.split_to_numbers_whitespace().collect())
+            .collect::<Vec<Vec<i64>>>();
+        assert_eq!(lines, vec![vec![7, 6, 4], vec![1, 2]]);
+    }
+}
@@ -0,0 +1,258 @@
+//! Small parser combinators working on the cursor of a [`Parser`].
+//!
+//! Every combinator leaves the cursor where it started when it fails, so alternatives can be tried
+//! one after the other.
+//!
+//! ```
+//! use parser::combinator::{line, literal, number, repeat, separated_by, Combinator};
+//! use parser::Parser;
+//!
+//! let mut parser = Parser::new("Program: 0,1,5\nProgram: 4");
+//! let program = line(|p: &mut Parser| {
+//!     literal("Program: ").parse(p)?;
+//!     separated_by(number::<u8>(), literal(",")).parse(p)
+//! });
+//! assert_eq!(repeat(program).parse(&mut parser), Ok(vec![vec![0, 1, 5], vec![4]]));
+//! ```
+use crate::{ParseError, Parser};
+use std::str::FromStr;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+pub trait Combinator<T> {
+    fn parse(&self, parser: &mut Parser) -> ParseResult<T>;
+}
+
+impl<T, F: Fn(&mut Parser) -> ParseResult<T>> Combinator<T> for F {
+    fn parse(&self, parser: &mut Parser) -> ParseResult<T> {
+        self(parser)
+    }
+}
+
+/// Runs `combinator` and moves the cursor back to where it was if it fails
+fn attempt<T>(parser: &mut Parser, combinator: &impl Combinator<T>) -> ParseResult<T> {
+    let start = parser.cursor();
+    let result = combinator.parse(parser);
+    if result.is_err() {
+        parser.go_to(start);
+    }
+    result
+}
+
+pub fn literal<'a>(target: &'a str) -> impl Fn(&mut Parser) -> ParseResult<&'a str> + 'a {
+    move |parser| {
+        for (i, c) in target.chars().enumerate() {
+            if parser.peek_at(i as i32) != Some(&c) {
+                parser.advance(i);
+                let error = ParseError::at(parser, format!("\"{}\"", target));
+                parser.go_back(i);
+                return Err(error);
+            }
+        }
+        parser.advance(target.chars().count());
+        Ok(target)
+    }
+}
+
+/// Matches a number with an optional sign
+pub fn number<T: FromStr>() -> impl Fn(&mut Parser) -> ParseResult<T> {
+    |parser| {
+        let start = parser.cursor();
+        parser.match_signed_number().ok_or_else(|| {
+            parser.go_to(start);
+            ParseError::at(parser, "number")
+        })
+    }
+}
+
+pub fn map<T, U>(
+    combinator: impl Combinator<T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&mut Parser) -> ParseResult<U> {
+    move |parser| combinator.parse(parser).map(&f)
+}
+
+/// Matches `item` zero or more times
+pub fn repeat<T>(item: impl Combinator<T>) -> impl Fn(&mut Parser) -> ParseResult<Vec<T>> {
+    move |parser| {
+        let mut items = vec![];
+        while !parser.is_done() {
+            let start = parser.cursor();
+            match attempt(parser, &item) {
+                Ok(value) if parser.cursor() != start => items.push(value),
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+}
+
+/// Matches `item` one or more times with `separator` in between
+pub fn separated_by<T, S>(
+    item: impl Combinator<T>,
+    separator: impl Combinator<S>,
+) -> impl Fn(&mut Parser) -> ParseResult<Vec<T>> {
+    move |parser| {
+        let mut items = vec![attempt(parser, &item)?];
+        loop {
+            let start = parser.cursor();
+            if attempt(parser, &separator).is_err() {
+                break;
+            }
+            match attempt(parser, &item) {
+                Ok(value) => items.push(value),
+                Err(_) => {
+                    parser.go_to(start);
+                    break;
+                }
+            }
+        }
+        Ok(items)
+    }
+}
+
+pub fn optional<T>(item: impl Combinator<T>) -> impl Fn(&mut Parser) -> ParseResult<Option<T>> {
+    move |parser| Ok(attempt(parser, &item).ok())
+}
+
+/// Tries `first` and falls back to `second`, reporting the error of whichever got further
+pub fn alt<T>(
+    first: impl Combinator<T>,
+    second: impl Combinator<T>,
+) -> impl Fn(&mut Parser) -> ParseResult<T> {
+    move |parser| {
+        let first_error = match attempt(parser, &first) {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        attempt(parser, &second).map_err(|second_error| {
+            if (first_error.line, first_error.column) > (second_error.line, second_error.column) {
+                first_error
+            } else {
+                second_error
+            }
+        })
+    }
+}
+
+/// Matches `item` followed by the end of the line or the input
+pub fn line<T>(item: impl Combinator<T>) -> impl Fn(&mut Parser) -> ParseResult<T> {
+    move |parser| {
+        let start = parser.cursor();
+        let value = attempt(parser, &item)?;
+        match parser.peek() {
+            None => Ok(value),
+            Some('\n') => {
+                parser.advance(1);
+                Ok(value)
+            }
+            Some(_) => {
+                let error = ParseError::at(parser, "end of line");
+                parser.go_to(start);
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Matches `item` followed by a blank line or the end of the input
+pub fn block<T>(item: impl Combinator<T>) -> impl Fn(&mut Parser) -> ParseResult<T> {
+    move |parser| {
+        let start = parser.cursor();
+        let value = attempt(parser, &item)?;
+        // the first newline may already have been consumed by a `line` inside `item`
+        let mut newlines = usize::from(parser.peek_at(-1) == Some(&'\n'));
+        while parser.peek() == Some(&'\n') {
+            parser.advance(1);
+            newlines += 1;
+        }
+        if parser.is_done() || newlines >= 2 {
+            return Ok(value);
+        }
+        let error = ParseError::at(parser, "blank line");
+        parser.go_to(start);
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal() {
+        let mut parser = Parser::new("Register A: 729");
+        assert_eq!(literal("Register ").parse(&mut parser), Ok("Register "));
+        let error = literal("B:").parse(&mut parser).unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (10, "'A'"));
+        assert_eq!(parser.cursor(), 9);
+    }
+
+    #[test]
+    fn test_number() {
+        let mut parser = Parser::new("-12,x");
+        assert_eq!(number::<i64>().parse(&mut parser), Ok(-12));
+        parser.advance(1);
+        assert!(number::<i64>().parse(&mut parser).is_err());
+        assert_eq!(parser.cursor(), 4);
+    }
+
+    #[test]
+    fn test_separated_by() {
+        let mut parser = Parser::new("1,2,3,x");
+        let numbers = separated_by(number::<u8>(), literal(","));
+        assert_eq!(numbers.parse(&mut parser), Ok(vec![1, 2, 3]));
+        assert_eq!(parser.cursor(), 5);
+    }
+
+    #[test]
+    fn test_optional_and_alt() {
+        let mut parser = Parser::new("do()don't()");
+        let instruction = alt(
+            map(literal("do()"), |_| true),
+            map(literal("don't()"), |_| false),
+        );
+        assert_eq!(
+            repeat(&instruction).parse(&mut parser),
+            Ok(vec![true, false])
+        );
+        assert_eq!(optional(&instruction).parse(&mut parser), Ok(None));
+    }
+
+    #[test]
+    fn test_alt_reports_furthest_error() {
+        let mut parser = Parser::new("mul(2;");
+        let mul = |p: &mut Parser| {
+            literal("mul(").parse(p)?;
+            number::<i64>().parse(p)?;
+            literal(",").parse(p)
+        };
+        let error = alt(literal("do()"), mul).parse(&mut parser).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (6, "\",\""));
+    }
+
+    #[test]
+    fn test_line_and_block() {
+        let mut parser = Parser::new("Register A: 4\nRegister B: 0\n\nProgram: 0,1");
+        let register = line(|p: &mut Parser| {
+            literal("Register ").parse(p)?;
+            parser_char(p)?;
+            literal(": ").parse(p)?;
+            number::<i64>().parse(p)
+        });
+        assert_eq!(block(repeat(register)).parse(&mut parser), Ok(vec![4, 0]));
+        let program = |p: &mut Parser| {
+            literal("Program: ").parse(p)?;
+            separated_by(number::<u8>(), literal(",")).parse(p)
+        };
+        assert_eq!(block(program).parse(&mut parser), Ok(vec![0, 1]));
+        assert!(parser.is_done());
+    }
+
+    fn parser_char(parser: &mut Parser) -> ParseResult<char> {
+        parser
+            .pop()
+            .copied()
+            .ok_or_else(|| ParseError::at(parser, "character"))
+    }
+}
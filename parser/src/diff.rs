@@ -0,0 +1,253 @@
+use crate::{MultiLineParser, Point};
+use std::collections::HashMap;
+
+/// How a cell changed between two grids, missing cells and the background counting as blank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// A character appeared on a blank cell
+    Added,
+    /// A character left the cell blank
+    Removed,
+    /// The new character is one that left another changed cell
+    Moved,
+    /// The character was swapped for an unrelated one
+    Replaced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellChange {
+    pub point: Point,
+    pub before: Option<char>,
+    pub after: Option<char>,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// The new grid, showing removed characters in place of the blanks they left
+    Inline,
+    /// The old grid next to the new one
+    SideBySide,
+}
+
+fn blank(c: Option<char>, background: char) -> Option<char> {
+    c.filter(|&c| c != background)
+}
+
+fn size(parser: &MultiLineParser) -> Point {
+    let width = parser.lines().iter().map(|l| l.len()).max().unwrap_or(0);
+    (parser.len(), width)
+}
+
+/// The cells that differ between `before` and `after`, row by row, treating `background` (`'.'`
+/// in most puzzles) as an empty cell.
+///
+/// A character that leaves one cell and arrives at another is paired up as `Moved`, in row order
+/// when several of the same character move at once.
+pub fn diff(
+    before: &MultiLineParser,
+    after: &MultiLineParser,
+    background: char,
+) -> Vec<CellChange> {
+    let (before_height, before_width) = size(before);
+    let (after_height, after_width) = size(after);
+    let height = before_height.max(after_height);
+    let width = before_width.max(after_width);
+
+    let changed = (0..height * width)
+        .map(|i| (i / width, i % width))
+        .map(|point| (point, before.get(point), after.get(point)))
+        .filter(|(_, old, new)| blank(*old, background) != blank(*new, background))
+        .collect::<Vec<_>>();
+
+    let mut departed: HashMap<char, usize> = HashMap::new();
+    for (_, old, _) in &changed {
+        if let Some(c) = blank(*old, background) {
+            *departed.entry(c).or_default() += 1;
+        }
+    }
+
+    changed
+        .into_iter()
+        .map(|(point, old, new)| {
+            let moved = blank(new, background)
+                .and_then(|c| departed.get_mut(&c))
+                .filter(|count| **count > 0)
+                .map(|count| *count -= 1)
+                .is_some();
+            let kind = match (blank(old, background), blank(new, background)) {
+                _ if moved => ChangeKind::Moved,
+                (None, _) => ChangeKind::Added,
+                (_, None) => ChangeKind::Removed,
+                _ => ChangeKind::Replaced,
+            };
+            CellChange {
+                point,
+                before: old,
+                after: new,
+                kind,
+            }
+        })
+        .collect()
+}
+
+impl ChangeKind {
+    fn color(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "\x1b[32m",
+            ChangeKind::Removed => "\x1b[31m",
+            ChangeKind::Moved => "\x1b[33m",
+            ChangeKind::Replaced => "\x1b[36m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Colors the changed cells for the terminal: added green, removed red, moved yellow and
+/// replaced cyan.
+///
+/// The old side of `SideBySide` shows where characters left from, yellow if they moved and red
+/// otherwise.
+pub fn render_diff(
+    before: &MultiLineParser,
+    after: &MultiLineParser,
+    background: char,
+    layout: DiffLayout,
+) -> String {
+    let changes = diff(before, after, background);
+    let mut arrived: HashMap<char, usize> = HashMap::new();
+    for change in &changes {
+        if let Some(c) = blank(change.after, background) {
+            *arrived.entry(c).or_default() += 1;
+        }
+    }
+
+    let mut old_cells = HashMap::new();
+    let mut new_cells = HashMap::new();
+    for change in &changes {
+        if let Some(c) = blank(change.before, background) {
+            let moved = arrived.get_mut(&c).filter(|count| **count > 0);
+            let kind = match moved {
+                Some(count) => {
+                    *count -= 1;
+                    ChangeKind::Moved
+                }
+                None => ChangeKind::Removed,
+            };
+            old_cells.insert(change.point, (c, kind));
+        }
+        match (blank(change.after, background), change.before) {
+            (Some(c), _) => new_cells.insert(change.point, (c, change.kind)),
+            (None, Some(c)) if layout == DiffLayout::Inline => {
+                new_cells.insert(change.point, (c, change.kind))
+            }
+            _ => None,
+        };
+    }
+
+    let (before_height, before_width) = size(before);
+    let (after_height, after_width) = size(after);
+    let mut text = String::new();
+    for line in 0..before_height.max(after_height) {
+        if layout == DiffLayout::SideBySide {
+            render_line(
+                &mut text,
+                before,
+                line,
+                before_width,
+                background,
+                &old_cells,
+            );
+            text.push_str(" | ");
+        }
+        let width = after_width.max(before_width);
+        render_line(&mut text, after, line, width, background, &new_cells);
+        text.push('\n');
+    }
+    text
+}
+
+fn render_line(
+    text: &mut String,
+    parser: &MultiLineParser,
+    line: usize,
+    width: usize,
+    background: char,
+    highlighted: &HashMap<Point, (char, ChangeKind)>,
+) {
+    for column in 0..width {
+        match highlighted.get(&(line, column)) {
+            Some((c, kind)) => {
+                text.push_str(kind.color());
+                text.push(*c);
+                text.push_str(RESET);
+            }
+            None => text.push(parser.get((line, column)).unwrap_or(background)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changes(before: &str, after: &str, background: char) -> Vec<(Point, ChangeKind)> {
+        diff(
+            &MultiLineParser::new(before),
+            &MultiLineParser::new(after),
+            background,
+        )
+        .iter()
+        .map(|change| (change.point, change.kind))
+        .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        assert!(changes("#.O\n...", "#.O\n...", '.').is_empty());
+        assert_eq!(
+            changes("@O.\n...", ".@O\n...", '.'),
+            vec![
+                ((0, 0), ChangeKind::Removed),
+                ((0, 1), ChangeKind::Moved),
+                ((0, 2), ChangeKind::Moved)
+            ]
+        );
+        assert_eq!(
+            changes("..\n..", "x.\n..", '.'),
+            vec![((0, 0), ChangeKind::Added)]
+        );
+        assert_eq!(
+            changes("ab\ncd", "xb\nc", ' '),
+            vec![
+                ((0, 0), ChangeKind::Replaced),
+                ((1, 1), ChangeKind::Removed)
+            ]
+        );
+        assert_eq!(
+            changes("ab", "ab\nc", ' '),
+            vec![((1, 0), ChangeKind::Added)]
+        );
+    }
+
+    #[test]
+    fn test_render_inline() {
+        let before = MultiLineParser::new("ab\ncd");
+        let after = MultiLineParser::new("ba\nc");
+        assert_eq!(
+            render_diff(&before, &after, ' ', DiffLayout::Inline),
+            "\x1b[33mb\x1b[0m\x1b[33ma\x1b[0m\nc\x1b[31md\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_render_side_by_side() {
+        let before = MultiLineParser::new("a.\n..");
+        let after = MultiLineParser::new("..\n.x");
+        assert_eq!(
+            render_diff(&before, &after, '.', DiffLayout::SideBySide),
+            "\x1b[31ma\x1b[0m. | ..\n.. | .\x1b[32mx\x1b[0m\n"
+        );
+    }
+}
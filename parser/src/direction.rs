@@ -1,3 +1,5 @@
+use crate::PointI64;
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum Direction {
     Right,
@@ -29,6 +31,20 @@ impl Direction {
         }
     }
 
+    /// The change of `(line, column)` when moving one step in this direction
+    pub fn offset(&self) -> PointI64 {
+        match self {
+            Right => (0, 1),
+            RightDown => (1, 1),
+            Down => (1, 0),
+            DownLeft => (1, -1),
+            Left => (0, -1),
+            LeftUp => (-1, -1),
+            Up => (-1, 0),
+            UpRight => (-1, 1),
+        }
+    }
+
     pub fn next_4(&self) -> Self {
         match self {
             Direction::Right => Direction::Down,
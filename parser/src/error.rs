@@ -0,0 +1,102 @@
+use crate::Parser;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Creates an error at the parser's cursor, with 1-based line and column
+    pub fn at(parser: &Parser, expected: impl Into<String>) -> Self {
+        Self::at_cursor(parser, parser.cursor(), expected)
+    }
+
+    /// Creates an error at `cursor` instead of the parser's cursor, borrowing the characters so a
+    /// failed attempt costs no more than scanning up to it
+    pub(crate) fn at_cursor(parser: &Parser, cursor: usize, expected: impl Into<String>) -> Self {
+        let chars = parser.as_chars();
+        let before = &chars[..cursor];
+        let line = before.iter().filter(|&&c| c == '\n').count() + 1;
+        let column = before.iter().rev().take_while(|&&c| c != '\n').count() + 1;
+        let snippet = chars[cursor + 1 - column..]
+            .iter()
+            .take_while(|&&c| c != '\n')
+            .collect();
+        let found = match chars.get(cursor) {
+            Some('\n') => "end of line".to_string(),
+            Some(c) => format!("'{}'", c),
+            None => "end of input".to_string(),
+        };
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+            snippet,
+        }
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_found(mut self, found: impl Into<String>) -> Self {
+        self.found = found.into();
+        self
+    }
+
+    /// Renders the offending line with a caret under the column of the error
+    pub fn render(&self) -> String {
+        format!(
+            "{}\n{}\n{}^",
+            self,
+            self.snippet,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let mut parser = Parser::new("ab\ncd");
+        parser.advance(4);
+        let error = ParseError::at(&parser, "digit");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected digit, found 'd'"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let mut parser = Parser::new("p=6,3 w=-1,-3");
+        parser.advance(6);
+        assert_eq!(
+            ParseError::at(&parser, "\" v=\"").render(),
+            "line 1, column 7: expected \" v=\", found 'w'\np=6,3 w=-1,-3\n      ^"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Direction, MultiLineParser, Nearable, Parser, Point};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells stored row by row in one vector, indexed by `(line, column)` points like
+/// the cursor of a `MultiLineParser`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid without any cell
+    pub fn empty() -> Self {
+        Self {
+            cells: vec![],
+            height: 0,
+            width: 0,
+        }
+    }
+
+    /// Builds a grid by calling `f` with the point of every cell
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height * width)
+            .map(|i| f((i / width, i % width)))
+            .collect();
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Converts every character of `parser`, reading past the end of shorter lines as spaces
+    pub fn from_parser(parser: &MultiLineParser, f: impl Fn(char) -> T) -> Self {
+        let height = parser.len();
+        let width = parser.lines().iter().map(|l| l.len()).max().unwrap_or(0);
+        let cells = parser
+            .lines()
+            .iter()
+            .flat_map(|line| (0..width).map(move |i| line.get(i).copied().unwrap_or(' ')))
+            .map(f)
+            .collect();
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.0 < self.height && point.1 < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.0 * self.width + point.1])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.0 * self.width + point.1])
+    }
+
+    /// The point next to `point` in `direction`, if it is on the grid
+    pub fn step(&self, point: Point, direction: &Direction) -> Option<Point> {
+        let next = point.checked_with_direction(direction)?;
+        self.contains(next).then_some(next)
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_4)
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions
+            .iter()
+            .filter_map(move |direction| self.step(point, direction))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// Every cell with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+}
+
+impl From<&MultiLineParser> for Grid<char> {
+    fn from(parser: &MultiLineParser) -> Self {
+        Grid::from_parser(parser, |c| c)
+    }
+}
+
+impl From<&Grid<char>> for MultiLineParser {
+    fn from(grid: &Grid<char>) -> Self {
+        let lines = (0..grid.height()).map(|line| {
+            let line = (0..grid.width()).map(|column| grid[(line, column)]);
+            Parser::new_untrimmed(&line.collect::<String>())
+        });
+        MultiLineParser::from_parsers(lines.collect())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "point {:?} outside of {}x{} grid",
+                point, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {:?} outside of {}x{} grid", point, height, width))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parser() {
+        let parser = MultiLineParser::new("0123\n1234\n89");
+        let grid = Grid::from_parser(&parser, |c| c.to_digit(10));
+        assert_eq!((grid.height(), grid.width()), (3, 4));
+        assert_eq!(grid[(1, 3)], Some(4));
+        assert_eq!(grid[(2, 3)], None);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(Grid::from(&parser).to_string(), "0123\n1234\n89  \n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::from(&MultiLineParser::new("abc\ndef"));
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![((0, 1), &'b'), ((1, 0), &'d')]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_map_find_and_mutate() {
+        let mut visited = Grid::new(2, 3, false);
+        visited[(1, 2)] = true;
+        *visited.get_mut((0, 1)).unwrap() = true;
+        assert_eq!(visited.find(|&v| v), Some((0, 1)));
+        assert_eq!(
+            visited.map(|&v| if v { '#' } else { '.' }).to_string(),
+            ".#.\n..#\n"
+        );
+        assert_eq!(visited.iter().filter(|(_, &v)| v).count(), 2);
+    }
+}
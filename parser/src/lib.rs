@@ -1,8 +1,31 @@
+mod byte_parser;
+pub mod combinator;
+mod diff;
 mod direction;
+mod error;
+mod grid;
 mod map;
 mod multi_line_parser;
 mod parser;
+mod ray;
+mod regions;
+pub mod scan;
+mod sections;
+mod sparse_grid;
+pub mod token;
+mod transform;
+mod wrapping;
+pub use byte_parser::ByteParser;
+pub use diff::{diff, render_diff, CellChange, ChangeKind, DiffLayout};
 pub use direction::Direction;
+pub use error::ParseError;
+pub use grid::Grid;
 pub use map::{Clusters, Map, Nearable, Point, PointI64, Solvable};
 pub use multi_line_parser::MultiLineParser;
 pub use parser::Parser;
+pub use ray::{bresenham, ray};
+pub use regex::Regex;
+pub use regions::{Connectivity, Region, Regions};
+pub use sections::{FromSection, Sections};
+pub use sparse_grid::SparseGrid;
+pub use wrapping::{wrap, Tiled, Torus};
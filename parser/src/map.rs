@@ -1,5 +1,6 @@
-use crate::{Direction, MultiLineParser};
-use std::collections::{HashMap, VecDeque};
+use crate::wrapping::wrap;
+use crate::{Connectivity, Direction, MultiLineParser};
+use std::collections::HashMap;
 pub type Point = (usize, usize);
 pub type PointI64 = (i64, i64);
 pub type Map = HashMap<char, Vec<Point>>;
@@ -24,7 +25,15 @@ pub trait Nearable {
     fn down(&self) -> Self;
     fn left(&self) -> Self;
     fn right(&self) -> Self;
+    /// Panics when moving past the top or left edge, see `checked_with_direction`
     fn with_direction(&self, direction: &Direction) -> Self;
+    /// Like `with_direction`, but `None` instead of underflowing past the top or left edge
+    fn checked_with_direction(&self, direction: &Direction) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(self.with_direction(direction))
+    }
     fn with_velocity(&self, velocity: &PointI64, max: &Self) -> Self;
 }
 
@@ -35,99 +44,58 @@ impl Nearable for Point {
     }
 
     fn up(&self) -> Point {
-        (self.0 - 1, self.1)
+        self.with_direction(&Direction::Up)
     }
 
     fn down(&self) -> Point {
-        (self.0 + 1, self.1)
+        self.with_direction(&Direction::Down)
     }
 
     fn left(&self) -> Point {
-        (self.0, self.1 - 1)
+        self.with_direction(&Direction::Left)
     }
 
     fn right(&self) -> Point {
-        (self.0, self.1 + 1)
+        self.with_direction(&Direction::Right)
     }
 
     fn with_direction(&self, direction: &Direction) -> Self {
-        match direction {
-            Direction::Right => self.right(),
-            Direction::RightDown => self.right().down(),
-            Direction::Down => self.down(),
-            Direction::DownLeft => self.down().left(),
-            Direction::Left => self.left(),
-            Direction::LeftUp => self.left().up(),
-            Direction::Up => self.up(),
-            Direction::UpRight => self.up().right(),
-        }
+        self.checked_with_direction(direction)
+            .expect("moved past the top or left edge")
     }
 
-    fn with_velocity(&self, velocity: &PointI64, max: &Point) -> Point {
-        let mut point = *self;
-        if velocity.0 < 0 && (self.0 as i64) < -velocity.0 {
-            point.0 = (max.0 + self.0) - ((-velocity.0) as usize);
-        } else {
-            point.0 = (self.0 as i64 + velocity.0) as usize % max.0;
-        }
-        if velocity.1 < 0 && (self.1 as i64) < -velocity.1 {
-            point.1 = (max.1 + self.1) - ((-velocity.1) as usize);
-        } else {
-            point.1 = (self.1 as i64 + velocity.1) as usize % max.1;
-        }
+    fn checked_with_direction(&self, direction: &Direction) -> Option<Point> {
+        let (line, column) = direction.offset();
+        Some((
+            self.0.checked_add_signed(line as isize)?,
+            self.1.checked_add_signed(column as isize)?,
+        ))
+    }
 
-        point
+    fn with_velocity(&self, velocity: &PointI64, max: &Point) -> Point {
+        wrap(
+            (self.0 as i64 + velocity.0, self.1 as i64 + velocity.1),
+            *max,
+        )
     }
 }
 
 impl From<&mut MultiLineParser> for Clusters {
     fn from(parser: &mut MultiLineParser) -> Self {
         let mut clusters: Clusters = HashMap::new();
-
-        let mut cloned_parser = parser.clone();
-        let mut added_point = vec![vec![false; parser.cursor_len()]; parser.len()];
-        for (char, point) in parser.iter() {
-            if added_point[point.0][point.1] {
-                continue;
-            }
-
-            cloned_parser.go_to(point);
-            let cluster = bfs(&mut cloned_parser, &char);
-            cluster.iter().for_each(|p| added_point[p.0][p.1] = true);
-            clusters.entry(char).or_default().push(cluster);
+        for region in parser.regions(Connectivity::Four).iter() {
+            let point = region.points()[0];
+            let char = parser.get(point).unwrap();
+            clusters
+                .entry(char)
+                .or_default()
+                .push(region.points().to_vec());
         }
         parser.reset();
         clusters
     }
 }
 
-fn bfs(parser: &mut MultiLineParser, needle: &char) -> Cluster {
-    let mut seen = vec![vec![false; parser.cursor_len()]; parser.len()];
-    let mut added = vec![vec![false; parser.cursor_len()]; parser.len()];
-    let mut queue = VecDeque::new();
-    queue.push_back(parser.point());
-    let mut cluster = vec![parser.point()];
-    while !queue.is_empty() {
-        let curr = queue.pop_front().unwrap();
-        parser.go_to(curr);
-        seen[curr.0][curr.1] = true;
-        for direction in Direction::VALUES_4 {
-            let next = parser.peek_next_with_direction(&direction);
-            if next == Some(needle) {
-                let point = parser.point().with_direction(&direction);
-
-                if seen[point.0][point.1] || added[point.0][point.1] {
-                    continue;
-                }
-                queue.push_back(point);
-                cluster.push(point);
-                added[point.0][point.1] = true;
-            }
-        }
-    }
-    cluster
-}
-
 pub trait Solvable: Sized {
     fn solve_equation(&self, vector_a: &Self, vector_b: &Self) -> Option<Self>;
 }
@@ -152,7 +120,7 @@ impl Solvable for PointI64 {
     }
 }
 
-fn to_i64(point: &Point) -> PointI64 {
+pub(crate) fn to_i64(point: &Point) -> PointI64 {
     (point.0 as i64, point.1 as i64)
 }
 
@@ -181,11 +149,33 @@ mod tests {
         assert_eq!((19, 26).solve_equation(&a, &b), None);
     }
 
+    #[test]
+    fn test_checked_with_direction() {
+        assert_eq!((0, 0).checked_with_direction(&Direction::Up), None);
+        assert_eq!(
+            (0, 3).checked_with_direction(&Direction::DownLeft),
+            Some((1, 2))
+        );
+        assert_eq!((2, 0).checked_with_direction(&Direction::LeftUp), None);
+    }
+
+    #[test]
+    fn test_clusters() {
+        let mut parser = MultiLineParser::new("aaa\nb");
+        parser.go_to((0, 2));
+        let clusters = Clusters::from(&mut parser);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[&'a'], vec![vec![(0, 0), (0, 1), (0, 2)]]);
+        assert_eq!(clusters[&'b'], vec![vec![(1, 0)]]);
+        assert_eq!(parser.point(), (0, 0));
+    }
+
     #[test]
     fn test_move_with_velocity() {
         assert_eq!((5, 5).with_velocity(&(-5, -5), &(10, 10)), (0, 0));
         assert_eq!((5, 5).with_velocity(&(-6, -6), &(10, 10)), (9, 9));
         assert_eq!((5, 5).with_velocity(&(4, 4), &(10, 10)), (9, 9));
         assert_eq!((5, 5).with_velocity(&(5, 5), &(10, 10)), (0, 0));
+        assert_eq!((1, 1).with_velocity(&(-25, -25), &(10, 10)), (6, 6));
     }
 }
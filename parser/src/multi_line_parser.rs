@@ -1,16 +1,31 @@
+use crate::scan::{Captures, FromCaptures, Template};
 use crate::Direction;
+use crate::Nearable;
+use crate::ParseError;
 use crate::Parser;
 use crate::Point;
+use regex::Regex;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use Direction::{Down, DownLeft, Left, LeftUp, Right, RightDown, Up, UpRight};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct MultiLineParser {
     parsers: Vec<Parser>,
     line: usize,
+    // input line of each parser, empty when no blank lines were skipped
+    numbers: Vec<usize>,
 }
 
+impl PartialEq for MultiLineParser {
+    fn eq(&self, other: &Self) -> bool {
+        self.parsers == other.parsers && self.line == other.line
+    }
+}
+
+impl Eq for MultiLineParser {}
+
 impl Display for MultiLineParser {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for parser in &self.parsers {
@@ -38,18 +53,54 @@ impl<'a> Iterator for MultiLineParserIterator<'a> {
 
 impl MultiLineParser {
     pub fn new(str: &str) -> Self {
-        let parsers = str
+        let (numbers, parsers) = str
             .split('\n')
-            .filter(|s| !s.is_empty())
-            .map(Parser::new)
-            .collect();
+            .enumerate()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(i, s)| (i + 1, Parser::new(s)))
+            .unzip();
+
+        MultiLineParser {
+            parsers,
+            line: 0,
+            numbers,
+        }
+    }
 
-        MultiLineParser { parsers, line: 0 }
+    /// Like `new`, but keeps whitespace and inner blank lines, padding every line with `fill`
+    pub fn new_preserving(str: &str, fill: char) -> Self {
+        let str = str.trim_matches(['\n', '\r']);
+        if str.is_empty() {
+            return MultiLineParser::from_parsers(vec![]);
+        }
+        let parsers = str
+            .split('\n')
+            .map(|s| Parser::new_untrimmed(s.trim_end_matches('\r')))
+            .collect::<Vec<Parser>>();
+        let mut parser = MultiLineParser::from_parsers(parsers);
+        let width = parser.width();
+        for line in &mut parser.parsers {
+            line.pad_end(width, fill);
+        }
+        parser
     }
 
     pub fn create(char: char, len: Point) -> Self {
         let parsers = vec![Parser::create(char, len.1); len.0];
-        MultiLineParser { parsers, line: 0 }
+        MultiLineParser::from_parsers(parsers)
+    }
+
+    pub(crate) fn from_parsers(parsers: Vec<Parser>) -> Self {
+        MultiLineParser {
+            parsers,
+            line: 0,
+            numbers: vec![],
+        }
+    }
+
+    /// The 1-based input line the parser at `index` was read from
+    fn line_number(&self, index: usize) -> usize {
+        self.numbers.get(index).copied().unwrap_or(index + 1)
     }
 
     pub fn pop_line(&mut self) -> Option<Parser> {
@@ -67,6 +118,22 @@ impl MultiLineParser {
         self.parsers.len()
     }
 
+    pub fn lines(&self) -> &[Parser] {
+        &self.parsers
+    }
+
+    /// A fast hash of the characters only, ignoring the cursor, for remembering grid states
+    pub fn content_hash(&self) -> u64 {
+        const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+        let mix = |hash: u64, value: u64| (hash.rotate_left(5) ^ value).wrapping_mul(SEED);
+        self.parsers.iter().fold(0, |hash, line| {
+            let hash = (0..line.len())
+                .filter_map(|i| line.get(i))
+                .fold(hash, |hash, &c| mix(hash, c as u64));
+            mix(hash, line.len() as u64)
+        })
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -79,10 +146,31 @@ impl MultiLineParser {
         self.parsers[0].len()
     }
 
-    pub fn match_number(&mut self) -> Vec<Option<i64>> {
+    pub fn match_number<T: FromStr>(&mut self) -> Vec<Option<T>> {
         self.parsers.iter_mut().map(|p| p.match_number()).collect()
     }
 
+    pub fn match_signed_number<T: FromStr>(&mut self) -> Vec<Option<T>> {
+        self.parsers
+            .iter_mut()
+            .map(|p| p.match_signed_number())
+            .collect()
+    }
+
+    pub fn skip_whitespace(&mut self) -> &mut Self {
+        self.parsers.iter_mut().for_each(|p| {
+            p.skip_whitespace();
+        });
+        self
+    }
+
+    pub fn extract_all_numbers(&self) -> Vec<Vec<i64>> {
+        self.parsers
+            .iter()
+            .map(|p| p.extract_all_numbers())
+            .collect()
+    }
+
     pub fn to_chars(&mut self) -> Vec<Vec<char>> {
         self.parsers.iter_mut().map(|p| p.chars()).collect()
     }
@@ -120,6 +208,134 @@ impl MultiLineParser {
             .collect()
     }
 
+    /// The numbers of each whitespace separated column, panicking on a ragged or non-numeric line
+    pub fn split_columns_whitespace(&self) -> Vec<Vec<i64>> {
+        self.try_split_columns_whitespace().unwrap()
+    }
+
+    /// Like `split_columns_whitespace`, but returns the first bad line as an error
+    pub fn try_split_columns_whitespace(&self) -> Result<Vec<Vec<i64>>, ParseError> {
+        let rows = self.try_split_to_numbers_whitespace()?;
+        let width = rows.first().map_or(0, Vec::len);
+        let mut columns = vec![vec![]; width];
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                let line = &self.parsers[i];
+                return Err(
+                    ParseError::at_cursor(line, line.len(), format!("{} numbers", width))
+                        .with_found(format!("{} numbers", row.len()))
+                        .with_line(self.line_number(i)),
+                );
+            }
+            for (column, number) in columns.iter_mut().zip(row) {
+                column.push(number);
+            }
+        }
+        Ok(columns)
+    }
+
+    /// The character at `index` of every line, reading past the end of shorter lines as spaces
+    pub fn column(&self, index: usize) -> Vec<char> {
+        self.parsers
+            .iter()
+            .map(|p| p.get(index).copied().unwrap_or(' '))
+            .collect()
+    }
+
+    pub fn columns(&self) -> Vec<Vec<char>> {
+        (0..self.width()).map(|i| self.column(i)).collect()
+    }
+
+    pub fn transpose(&self) -> MultiLineParser {
+        let parsers = self
+            .columns()
+            .iter()
+            .map(|column| Parser::new_untrimmed(&column.iter().collect::<String>()))
+            .collect();
+        MultiLineParser::from_parsers(parsers)
+    }
+
+    /// Splits fixed-width input at the columns blank on every line, keeping the whitespace
+    pub fn column_blocks(&self) -> Vec<MultiLineParser> {
+        let columns = self.columns();
+        let mut blocks = vec![];
+        let mut start = None;
+        for i in 0..=columns.len() {
+            let blank = columns
+                .get(i)
+                .is_none_or(|column| column.iter().all(|c| c.is_whitespace()));
+            match (blank, start) {
+                (false, None) => start = Some(i),
+                (true, Some(from)) => {
+                    let parsers = (0..self.len())
+                        .map(|line| {
+                            Parser::new_untrimmed(
+                                &columns[from..i].iter().map(|c| c[line]).collect::<String>(),
+                            )
+                        })
+                        .collect();
+                    blocks.push(MultiLineParser::from_parsers(parsers));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        blocks
+    }
+
+    fn width(&self) -> usize {
+        self.parsers.iter().map(Parser::len).max().unwrap_or(0)
+    }
+
+    pub fn try_match_number<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        self.try_each_line(|p| p.try_match_number())
+    }
+
+    pub fn try_match_signed_number<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        self.try_each_line(|p| p.try_match_signed_number())
+    }
+
+    pub fn try_match_number_up_to(&mut self, target: char) -> Result<Vec<i64>, ParseError> {
+        self.try_each_line(|p| p.try_match_number_up_to(target))
+    }
+
+    pub fn try_split_to_numbers(&self, delimiter: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        self.parsers
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                p.try_split_to_numbers(delimiter)
+                    .map_err(|e| e.with_line(self.line_number(i)))
+            })
+            .collect()
+    }
+
+    pub fn try_split_to_numbers_whitespace(&self) -> Result<Vec<Vec<i64>>, ParseError> {
+        self.parsers
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                p.try_split_to_numbers_whitespace()
+                    .map_err(|e| e.with_line(self.line_number(i)))
+            })
+            .collect()
+    }
+
+    /// Applies `f` to every line, reporting the input line of the first error
+    fn try_each_line<T>(
+        &mut self,
+        f: impl Fn(&mut Parser) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let Self {
+            parsers, numbers, ..
+        } = self;
+        parsers
+            .iter_mut()
+            .enumerate()
+            .map(|(i, p)| f(p).map_err(|e| e.with_line(numbers.get(i).copied().unwrap_or(i + 1))))
+            .collect()
+    }
+
     pub fn split_to_strings(&self, delimiter: &str) -> Vec<Vec<String>> {
         self.parsers
             .iter()
@@ -127,6 +343,72 @@ impl MultiLineParser {
             .collect()
     }
 
+    /// Extracts typed values from every line following a template such as `"p={},{} v={},{}"`
+    pub fn scan<T: FromCaptures>(&self, template: &str) -> Result<Vec<T>, ParseError> {
+        let template = Template::new(template);
+        self.parsers
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                template
+                    .scan(p)
+                    .map_err(|e| e.with_line(self.line_number(i)))
+            })
+            .collect()
+    }
+
+    /// Converts the groups of the first match of `regex` on each line, moving past the match
+    pub fn map_lines_regex<T: FromCaptures>(
+        &mut self,
+        regex: &Regex,
+    ) -> Result<Vec<T>, ParseError> {
+        self.try_each_line(|p| {
+            let Some((mut groups, end)) = p.regex_groups(regex, false) else {
+                return Err(ParseError::at(p, format!("match of /{}/", regex)));
+            };
+            groups.remove(0);
+            let mut captures = Captures::from_values(p.clone(), groups);
+            p.go_to(end);
+            T::from_captures(&mut captures)
+        })
+    }
+
+    pub fn get(&self, point: Point) -> Option<char> {
+        self.parsers.get(point.0)?.get(point.1).copied()
+    }
+
+    /// Replaces the character at `point` without moving the cursor, ignoring points outside
+    pub fn set_at(&mut self, point: Point, target: char) {
+        if let Some(parser) = self.parsers.get_mut(point.0) {
+            parser.set_at(point.1, target);
+        }
+    }
+
+    /// The points next to `point` in each of `directions` that are inside, with their characters
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, char)> + 'a {
+        directions.iter().filter_map(move |direction| {
+            let next = point.checked_with_direction(direction)?;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// Every point holding `target`, line by line
+    pub fn find_all(&self, target: char) -> Vec<Point> {
+        self.parsers
+            .iter()
+            .enumerate()
+            .flat_map(|(line, parser)| {
+                (0..parser.len())
+                    .filter(move |&i| parser.get(i) == Some(&target))
+                    .map(move |i| (line, i))
+            })
+            .collect()
+    }
+
     pub fn peek(&self) -> Option<&char> {
         let parser = self.parsers.get(self.line);
         if let Some(parser) = parser {
@@ -234,42 +516,10 @@ impl MultiLineParser {
                 UpRight => self.peek_at(-i, i),
             };
             if value == Some(target) || value.is_none() {
-                let i = (i - 1) as usize;
                 let ret = value.is_some();
-                let cursor = self.cursor();
-                match direction {
-                    Right => {
-                        self.parsers[self.line].go_to(cursor + i);
-                    }
-                    RightDown => {
-                        self.line += i;
-                        self.parsers[self.line].go_to(cursor + i);
-                    }
-                    Down => {
-                        self.line += i;
-                        self.parsers[self.line].go_to(cursor);
-                    }
-                    DownLeft => {
-                        self.line += i;
-                        self.parsers[self.line].go_to(cursor - i);
-                    }
-                    Left => {
-                        self.parsers[self.line].go_to(cursor - i);
-                    }
-                    LeftUp => {
-                        self.line -= i;
-                        self.parsers[self.line].go_to(cursor - i);
-                    }
-                    Up => {
-                        self.line -= i;
-                        self.parsers[self.line].go_to(cursor);
-                    }
-                    UpRight => {
-                        self.line -= i;
-                        self.parsers[self.line].go_to(cursor + i);
-                    }
-                };
-
+                if let Some(next) = self.point_with_direction((i - 1) as usize, direction) {
+                    self.go_to(next);
+                }
                 return ret;
             }
             i += 1;
@@ -359,40 +609,30 @@ impl MultiLineParser {
         value
     }
 
+    /// The point `num` steps away in `direction`, or `None` past the top or left edge
+    fn point_with_direction(&self, num: usize, direction: &Direction) -> Option<Point> {
+        (0..num).try_fold(self.point(), |point, _| {
+            point.checked_with_direction(direction)
+        })
+    }
+
     pub fn advance_with_direction(&mut self, num: usize, direction: &Direction) {
-        let cursor = self.cursor();
-        match direction {
-            Right => {
-                self.parsers[self.line].go_to(cursor + num);
-            }
-            RightDown => {
-                self.line += num;
-                self.parsers[self.line].go_to(cursor + num);
-            }
-            Down => {
-                self.line += num;
-                self.parsers[self.line].go_to(cursor);
-            }
-            DownLeft => {
-                self.line += num;
-                self.parsers[self.line].go_to(cursor - num);
-            }
-            Left => {
-                self.parsers[self.line].go_to(cursor - num);
-            }
-            LeftUp => {
-                self.line -= num;
-                self.parsers[self.line].go_to(cursor - num);
-            }
-            Up => {
-                self.line -= num;
-                self.parsers[self.line].go_to(cursor);
-            }
-            UpRight => {
-                self.line -= num;
-                self.parsers[self.line].go_to(cursor + num);
-            }
-        };
+        let next = self
+            .point_with_direction(num, direction)
+            .expect("advanced past the top or left edge");
+        self.go_to(next);
+    }
+
+    /// Moves `num` steps in `direction` only if that lands on a character
+    pub fn try_advance_with_direction(
+        &mut self,
+        num: usize,
+        direction: &Direction,
+    ) -> Option<Point> {
+        let next = self.point_with_direction(num, direction)?;
+        self.get(next)?;
+        self.go_to(next);
+        Some(next)
     }
 
     pub fn swap(&mut self, point: Point) {
@@ -572,6 +812,148 @@ mod tests {
         assert_eq!(parser.diagonal_x_exists(vec!["ho", "wo"]), false);
     }
 
+    #[test]
+    fn test_match_signed_number() {
+        let mut parser = MultiLineParser::new("a: -12\nb:  7");
+        parser.advance_all_lines(2);
+        assert_eq!(
+            parser.skip_whitespace().match_signed_number::<i32>(),
+            vec![Some(-12), Some(7)]
+        );
+    }
+
+    #[test]
+    fn test_extract_all_numbers() {
+        let parser = MultiLineParser::new("p=0,4 v=3,-3\np=6,3 v=-1,-3");
+        assert_eq!(
+            parser.extract_all_numbers(),
+            vec![vec![0, 4, 3, -3], vec![6, 3, -1, -3]]
+        );
+    }
+
+    #[test]
+    fn test_new_preserving() {
+        let parser = MultiLineParser::new_preserving("\n123 328\n 45 64\n\n  6\r\n", '.');
+        assert_eq!(parser.to_string(), "123 328\n 45 64.\n.......\n  6....\n");
+        assert_eq!(parser.peek_at(1, 0), Some(&' '));
+        assert!(MultiLineParser::new_preserving("\n\n", ' ').is_empty());
+    }
+
+    #[test]
+    fn test_split_columns_whitespace() {
+        let parser = MultiLineParser::new("3   4\n4   3\n20  5");
+        assert_eq!(
+            parser.split_columns_whitespace(),
+            vec![vec![3, 4, 20], vec![4, 3, 5]]
+        );
+        let error = MultiLineParser::new("3   4\n4   3\n20  5 7")
+            .try_split_columns_whitespace()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: expected 2 numbers, found 3 numbers"
+        );
+    }
+
+    #[test]
+    fn test_columns_and_transpose() {
+        let parser = MultiLineParser::new("abc\nde");
+        assert_eq!(parser.column(2), vec!['c', ' ']);
+        assert_eq!(parser.columns().len(), 3);
+        assert_eq!(parser.transpose().to_string(), "ad\nbe\nc \n");
+        assert_eq!(parser.transpose().transpose().to_string(), "abc\nde \n");
+    }
+
+    #[test]
+    fn test_column_blocks() {
+        let parser = MultiLineParser::new_preserving("123 328  51\n 45 64  387\n  6 98  215", ' ');
+        let blocks = parser.column_blocks();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].to_string(), "123\n 45\n  6\n");
+        assert_eq!(blocks[1].to_string(), "328\n64 \n98 \n");
+        assert_eq!(
+            blocks[2].transpose().split_to_numbers_whitespace(),
+            vec![vec![32], vec![581], vec![175]]
+        );
+    }
+
+    #[test]
+    fn test_map_lines_regex() {
+        let mut parser = MultiLineParser::new("Button A: X+94, Y+34\nPrize: X=8400, Y=5400");
+        let regex = Regex::new(r"X[+=](\d+), Y[+=](\d+)").unwrap();
+        assert_eq!(
+            parser.map_lines_regex::<(usize, usize)>(&regex),
+            Ok(vec![(94, 34), (8400, 5400)])
+        );
+        assert_eq!(parser.cursor(), 20);
+        parser.reset();
+        let error = parser.map_lines_regex::<(u8, u8)>(&regex).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.found, "\"8400\"");
+    }
+
+    #[test]
+    fn test_point_accessors() {
+        let mut parser = MultiLineParser::new("#.#\n.S.\n#.#");
+        parser.advance(2);
+        assert_eq!(parser.get((1, 1)), Some('S'));
+        assert_eq!(parser.get((3, 0)), None);
+        parser.set_at((1, 1), '.');
+        assert_eq!(parser.get((1, 1)), Some('.'));
+        assert_eq!(
+            parser
+                .neighbors((0, 0), &Direction::VALUES_8)
+                .collect::<Vec<_>>(),
+            vec![((0, 1), '.'), ((1, 1), '.'), ((1, 0), '.')]
+        );
+        assert_eq!(parser.find_all('#'), vec![(0, 0), (0, 2), (2, 0), (2, 2)]);
+        assert_eq!(parser.point(), (0, 2));
+    }
+
+    #[test]
+    fn test_advance_to_with_direction_stops_at_edge() {
+        let mut parser = MultiLineParser::new("ab\ncd");
+        parser.go_to((1, 1));
+        assert!(!parser.adnvance_to_with_direction(&'x', &LeftUp));
+        assert_eq!(parser.point(), (0, 0));
+        assert!(parser.adnvance_to_with_direction(&'d', &RightDown));
+        assert_eq!(parser.point(), (0, 0));
+    }
+
+    #[test]
+    fn test_try_advance_with_direction() {
+        let mut parser = MultiLineParser::new("abc\ndef");
+        assert_eq!(parser.try_advance_with_direction(1, &Up), None);
+        assert_eq!(parser.try_advance_with_direction(3, &Right), None);
+        assert_eq!(parser.point(), (0, 0));
+        assert_eq!(
+            parser.try_advance_with_direction(1, &RightDown),
+            Some((1, 1))
+        );
+        assert_eq!(parser.try_advance_with_direction(1, &LeftUp), Some((0, 0)));
+        assert_eq!(parser.try_advance_with_direction(2, &Right), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_try_split_to_numbers() {
+        let parser = MultiLineParser::new("1,2\n3,4\n5,six");
+        let error = parser.try_split_to_numbers(",").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.render().lines().last(), Some("  ^"));
+        let parser = MultiLineParser::new("\n1,2\n\n3,4\nfive,6\n");
+        let error = parser.try_split_to_numbers(",").unwrap_err();
+        assert_eq!(error.line, 5);
+    }
+
+    #[test]
+    fn test_try_match_number_up_to() {
+        let mut parser = MultiLineParser::new("190: 10 19\n3267: 81 40");
+        assert_eq!(parser.try_match_number_up_to(':'), Ok(vec![190, 3267]));
+        parser.reset();
+        let error = parser.try_match_number_up_to(' ').unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+
     #[test]
     fn test_go_to_symmetrically() {
         let mut parser = MultiLineParser::new("hello\nworld\n12345");
@@ -584,4 +966,15 @@ mod tests {
         parser.go_to_symmetrically((-2, -2));
         assert_eq!(parser.peek(), Some(&'l'));
     }
+
+    #[test]
+    fn test_content_hash() {
+        let mut parser = MultiLineParser::new("ab\ncd");
+        let hash = parser.content_hash();
+        parser.go_to((1, 1));
+        assert_eq!(parser.content_hash(), hash);
+        assert_ne!(MultiLineParser::new("abc\nd").content_hash(), hash);
+        parser.set_at((0, 0), 'x');
+        assert_ne!(parser.content_hash(), hash);
+    }
 }
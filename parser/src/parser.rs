@@ -1,4 +1,7 @@
+use crate::ParseError;
+use regex::Regex;
 use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Parser {
@@ -39,6 +42,14 @@ impl Parser {
         }
     }
 
+    /// Keeps leading and trailing whitespace, which is meaningful in column-aligned input
+    pub fn new_untrimmed(str: &str) -> Self {
+        Self {
+            characters: str.chars().collect(),
+            cursor: 0,
+        }
+    }
+
     pub fn create(char: char, len: usize) -> Self {
         let characters = vec![char; len];
         Self {
@@ -47,10 +58,21 @@ impl Parser {
         }
     }
 
+    pub fn pad_end(&mut self, len: usize, fill: char) -> &mut Self {
+        if self.characters.len() < len {
+            self.characters.resize(len, fill);
+        }
+        self
+    }
+
     pub fn chars(&self) -> Vec<char> {
         self.characters.clone()
     }
 
+    pub(crate) fn as_chars(&self) -> &[char] {
+        &self.characters
+    }
+
     pub fn iter(&mut self) -> ParserIterator {
         ParserIterator { parser: self }
     }
@@ -77,6 +99,17 @@ impl Parser {
         }
     }
 
+    pub fn get(&self, index: usize) -> Option<&char> {
+        self.characters.get(index)
+    }
+
+    /// Ignores indexes past the end
+    pub fn set_at(&mut self, index: usize, target: char) {
+        if let Some(c) = self.characters.get_mut(index) {
+            *c = target;
+        }
+    }
+
     pub fn cursor(&self) -> usize {
         self.cursor
     }
@@ -159,7 +192,8 @@ impl Parser {
         result
     }
 
-    pub fn match_number(&mut self) -> Option<i64> {
+    pub fn match_number<T: FromStr>(&mut self) -> Option<T> {
+        let start = self.cursor;
         let mut number = String::new();
         while let Some(&c) = self.peek() {
             if char::is_digit(c, 10) {
@@ -169,7 +203,69 @@ impl Parser {
                 break;
             }
         }
-        number.parse().ok()
+        let number = number.parse().ok();
+        if number.is_none() {
+            self.cursor = start;
+        }
+        number
+    }
+
+    /// Leaves the cursor in place when no digits follow the sign or the number does not fit in `T`
+    pub fn match_signed_number<T: FromStr>(&mut self) -> Option<T> {
+        let start = self.cursor;
+        let sign = match self.peek() {
+            Some(&c) if c == '-' || c == '+' => {
+                self.cursor += 1;
+                c
+            }
+            _ => '+',
+        };
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.cursor = start;
+            return None;
+        }
+        let number = self.match_number::<String>()?;
+        let number = if sign == '-' {
+            format!("-{}", number).parse().ok()
+        } else {
+            number.parse().ok()
+        };
+        if number.is_none() {
+            self.cursor = start;
+        }
+        number
+    }
+
+    pub fn skip_whitespace(&mut self) -> &mut Self {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.cursor += 1;
+        }
+        self
+    }
+
+    /// Every signed integer left on the line, reading the `-` of ranges like `3-5` as a separator
+    pub fn extract_all_numbers(&self) -> Vec<i64> {
+        let mut numbers = vec![];
+        let mut number = String::new();
+        let mut previous = self.cursor.checked_sub(1).map(|i| self.characters[i]);
+        for &c in self.characters.iter().skip(self.cursor) {
+            if c.is_ascii_digit() {
+                number.push(c);
+            } else {
+                if let Ok(n) = number.parse() {
+                    numbers.push(n);
+                }
+                number.clear();
+                if c == '-' && !previous.is_some_and(|p| p.is_alphanumeric()) {
+                    number.push(c);
+                }
+            }
+            previous = Some(c);
+        }
+        if let Ok(n) = number.parse() {
+            numbers.push(n);
+        }
+        numbers
     }
 
     pub fn match_number_up_to(&mut self, target: char) -> Option<i64> {
@@ -201,6 +297,147 @@ impl Parser {
             .collect()
     }
 
+    /// Like `match_number`, but explains why no number was matched
+    pub fn try_match_number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.cursor;
+        let digits: String = self.match_number().unwrap_or_default();
+        digits.parse().map_err(|_| {
+            self.cursor = start;
+            let error = self.error_at(start, "number");
+            if digits.is_empty() {
+                error
+            } else {
+                error.with_found(format!("\"{}\", which overflows", digits))
+            }
+        })
+    }
+
+    pub fn try_match_signed_number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.cursor;
+        self.match_signed_number()
+            .ok_or_else(|| self.error_at(start, "number"))
+    }
+
+    /// Like `match_number_up_to`, but leaves the cursor in place and explains what went wrong
+    pub fn try_match_number_up_to(&mut self, target: char) -> Result<i64, ParseError> {
+        let start = self.cursor;
+        let number = self.try_match_number()?;
+        if self.peek() != Some(&target) {
+            let error = ParseError::at(self, format!("'{}'", target));
+            self.cursor = start;
+            return Err(error);
+        }
+        self.cursor += 1;
+        Ok(number)
+    }
+
+    pub fn try_split_to_numbers(&self, delimiter: &str) -> Result<Vec<i64>, ParseError> {
+        let mut cursor = self.cursor;
+        let tokens = self
+            .characters
+            .iter()
+            .skip(self.cursor)
+            .collect::<String>()
+            .split(delimiter)
+            .map(|token| {
+                let start = cursor;
+                cursor += token.chars().count() + delimiter.chars().count();
+                (token.to_string(), start)
+            })
+            .collect();
+        self.parse_tokens(tokens)
+    }
+
+    pub fn try_split_to_numbers_whitespace(&self) -> Result<Vec<i64>, ParseError> {
+        let mut tokens: Vec<(String, usize)> = vec![];
+        let mut in_token = false;
+        for (i, c) in self.characters.iter().enumerate().skip(self.cursor) {
+            match (c.is_whitespace(), in_token) {
+                (true, _) => in_token = false,
+                (false, true) => tokens.last_mut().unwrap().0.push(*c),
+                (false, false) => {
+                    tokens.push((c.to_string(), i));
+                    in_token = true;
+                }
+            }
+        }
+        self.parse_tokens(tokens)
+    }
+
+    fn parse_tokens(&self, tokens: Vec<(String, usize)>) -> Result<Vec<i64>, ParseError> {
+        tokens
+            .into_iter()
+            .map(|(token, start)| {
+                token.parse().map_err(|_| {
+                    self.error_at(start, "number")
+                        .with_found(format!("\"{}\"", token))
+                })
+            })
+            .collect()
+    }
+
+    /// Matches `regex` at the cursor, returning every group with the whole match first
+    pub fn match_regex(&mut self, regex: &Regex) -> Option<Vec<String>> {
+        let (groups, end) = self.regex_groups(regex, true)?;
+        self.cursor = end;
+        Some(groups.into_iter().map(|(text, _)| text).collect())
+    }
+
+    /// The groups of every match of `regex` in the remaining text, moving past the last match
+    pub fn all_captures(&mut self, regex: &Regex) -> Vec<Vec<String>> {
+        let rest = self
+            .characters
+            .get(self.cursor..)
+            .unwrap_or_default()
+            .iter()
+            .collect::<String>();
+        let mut end = self.cursor;
+        let matches = regex
+            .captures_iter(&rest)
+            .map(|captures| {
+                end = self.cursor + rest[..captures.get(0).unwrap().end()].chars().count();
+                captures
+                    .iter()
+                    .map(|group| group.map_or(String::new(), |g| g.as_str().to_string()))
+                    .collect()
+            })
+            .collect();
+        self.cursor = end;
+        matches
+    }
+
+    /// Every group of the first match with its starting cursor, and the cursor after the match
+    pub(crate) fn regex_groups(
+        &self,
+        regex: &Regex,
+        anchored: bool,
+    ) -> Option<(Vec<(String, usize)>, usize)> {
+        let rest = self
+            .characters
+            .get(self.cursor..)
+            .unwrap_or_default()
+            .iter()
+            .collect::<String>();
+        let captures = regex.captures(&rest)?;
+        let whole = captures.get(0).unwrap();
+        if anchored && whole.start() != 0 {
+            return None;
+        }
+        let to_cursor = |byte: usize| self.cursor + rest[..byte].chars().count();
+        let groups = captures
+            .iter()
+            .map(|group| match group {
+                Some(g) => (g.as_str().to_string(), to_cursor(g.start())),
+                None => (String::new(), to_cursor(whole.start())),
+            })
+            .collect();
+        Some((groups, to_cursor(whole.end())))
+    }
+
+    fn error_at(&self, cursor: usize, expected: &str) -> ParseError {
+        ParseError::at_cursor(self, cursor, expected)
+    }
+
     pub fn split_to_strings(&self, delimiter: &str) -> Vec<String> {
         self.characters
             .iter()
@@ -286,7 +523,7 @@ mod tests {
     fn test_match_number() {
         let mut parser = Parser::new("asd123hello");
         parser.advance(3);
-        assert_eq!(parser.match_number(), Some(123));
+        assert_eq!(parser.match_number::<i64>(), Some(123));
         assert_eq!(parser.cursor(), 6);
     }
 
@@ -298,6 +535,144 @@ mod tests {
         assert_eq!(parser.cursor(), 7);
     }
 
+    #[test]
+    fn test_match_number_generic() {
+        let mut parser = Parser::new("340282366920938463463374607431768211455");
+        assert_eq!(
+            parser.match_number::<u128>(),
+            Some(340282366920938463463374607431768211455)
+        );
+        let mut parser = Parser::new("123");
+        assert_eq!(parser.match_number::<u8>(), Some(123));
+        let mut parser = Parser::new("300");
+        assert_eq!(parser.match_number::<u8>(), None);
+        assert_eq!(parser.cursor(), 0);
+    }
+
+    #[test]
+    fn test_match_signed_number() {
+        let mut parser = Parser::new("v=3,-3 -x");
+        parser.advance(2);
+        assert_eq!(parser.match_signed_number::<i64>(), Some(3));
+        parser.advance(1);
+        assert_eq!(parser.match_signed_number::<i64>(), Some(-3));
+        parser.advance(1);
+        assert_eq!(parser.match_signed_number::<i64>(), None);
+        assert_eq!(parser.cursor(), 7);
+        let mut parser = Parser::new("-300");
+        assert_eq!(parser.match_signed_number::<i8>(), None);
+        assert_eq!(parser.cursor(), 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace() {
+        let mut parser = Parser::new("12   34");
+        parser.advance(2);
+        assert_eq!(parser.skip_whitespace().match_number::<u64>(), Some(34));
+    }
+
+    #[test]
+    fn test_extract_all_numbers() {
+        let parser = Parser::new("p=0,4 v=3,-3");
+        assert_eq!(parser.extract_all_numbers(), vec![0, 4, 3, -3]);
+        let parser = Parser::new("3-5 a-1 -7");
+        assert_eq!(parser.extract_all_numbers(), vec![3, 5, 1, -7]);
+        let parser = Parser::new("1 99999999999999999999 2");
+        assert_eq!(parser.extract_all_numbers(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_new_untrimmed() {
+        let mut parser = Parser::new_untrimmed(" 0A");
+        assert_eq!(parser.peek(), Some(&' '));
+        parser.pad_end(5, '.').pad_end(2, '#');
+        assert_eq!(parser.to_string(), " 0A..");
+    }
+
+    #[test]
+    fn test_match_regex() {
+        let regex = Regex::new(r"(\w+) (\d+)?").unwrap();
+        let mut parser = Parser::new("mul 12 x");
+        assert_eq!(
+            parser.match_regex(&regex),
+            Some(vec![
+                "mul 12".to_string(),
+                "mul".to_string(),
+                "12".to_string()
+            ])
+        );
+        assert_eq!(parser.cursor(), 6);
+        parser.advance(1);
+        assert_eq!(parser.match_regex(&Regex::new(r"\d").unwrap()), None);
+        assert_eq!(parser.cursor(), 7);
+    }
+
+    #[test]
+    fn test_all_captures() {
+        let regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        let mut parser = Parser::new("xmul(2,4)%&mul[3,7]!mul(11,8)end");
+        let numbers = parser
+            .all_captures(&regex)
+            .iter()
+            .map(|c| (c[1].clone(), c[2].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            numbers,
+            vec![("2".into(), "4".into()), ("11".into(), "8".into())]
+        );
+        assert_eq!(parser.peek(), Some(&'e'));
+        parser.go_to(100);
+        assert!(parser.all_captures(&regex).is_empty());
+    }
+
+    #[test]
+    fn test_try_match_number_up_to() {
+        let mut parser = Parser::new("12;34");
+        let error = parser.try_match_number_up_to(',').unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (3, "';'"));
+        assert_eq!(parser.cursor(), 0);
+        assert_eq!(parser.try_match_number_up_to(';'), Ok(12));
+        parser.advance(2);
+        assert_eq!(
+            parser.try_match_number::<u8>().unwrap_err().found,
+            "end of input"
+        );
+    }
+
+    #[test]
+    fn test_try_match_number_overflow() {
+        let mut parser = Parser::new("99999999999999999999x");
+        let error = parser.try_match_number::<u8>().unwrap_err();
+        assert_eq!(
+            (error.column, error.found.as_str()),
+            (1, "\"99999999999999999999\", which overflows")
+        );
+        assert_eq!(parser.cursor(), 0);
+        assert_eq!(parser.try_match_number::<u128>(), Ok(99999999999999999999));
+    }
+
+    #[test]
+    fn test_try_split_to_numbers() {
+        let parser = Parser::new("1,2,x3,4");
+        let error = parser.try_split_to_numbers(",").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (5, "\"x3\""));
+        assert_eq!(
+            Parser::new("1, 2, 3").try_split_to_numbers(", "),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_try_split_to_numbers_whitespace() {
+        let parser = Parser::new("7 6   4 2 a");
+        let error = parser.try_split_to_numbers_whitespace().unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (11, "\"a\""));
+        assert_eq!(
+            Parser::new("7 6   4").try_split_to_numbers_whitespace(),
+            Ok(vec![7, 6, 4])
+        );
+    }
+
     #[test]
     fn test_ignore_between() {
         let mut parser = Parser::new("hello[world]goodbye[world]");
@@ -0,0 +1,169 @@
+use crate::map::to_i64;
+use crate::{Direction, Grid, MultiLineParser, Point, PointI64};
+
+/// The points reached by repeatedly adding `step` to `from`, `from` excluded.
+///
+/// Stops before going past the top or left edge; bounding the other edges is up to the caller.
+/// A `(0, 0)` step yields nothing instead of repeating `from` forever.
+pub fn ray(from: Point, step: PointI64) -> impl Iterator<Item = Point> {
+    let mut point = Some(from).filter(|_| step != (0, 0));
+    std::iter::from_fn(move || {
+        let (line, column) = point?;
+        point = line
+            .checked_add_signed(step.0 as isize)
+            .zip(column.checked_add_signed(step.1 as isize));
+        point
+    })
+}
+
+/// The points of the straight line from `from` to `to`, both included, each one touching the
+/// previous one
+pub fn bresenham(from: PointI64, to: PointI64) -> Vec<PointI64> {
+    let (delta_line, delta_column) = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+    let (step_line, step_column) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+    let mut error = delta_line + delta_column;
+    let mut point = from;
+    let mut points = vec![from];
+    while point != to {
+        let doubled = 2 * error;
+        if doubled >= delta_column {
+            error += delta_column;
+            point.0 += step_line;
+        }
+        if doubled <= delta_line {
+            error += delta_line;
+            point.1 += step_column;
+        }
+        points.push(point);
+    }
+    points
+}
+
+impl<T> Grid<T> {
+    /// The cells from `from` (excluded) along `step` until the edge of the grid
+    pub fn ray(&self, from: Point, step: PointI64) -> impl Iterator<Item = (Point, &T)> {
+        ray(from, step).map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The first cell along `step` matching `is_hit`, if any before the edge
+    pub fn cast(
+        &self,
+        from: Point,
+        step: PointI64,
+        is_hit: impl Fn(&T) -> bool,
+    ) -> Option<(Point, &T)> {
+        self.ray(from, step).find(|(_, cell)| is_hit(cell))
+    }
+
+    /// The first cell matching `is_visible` in each of the 8 directions that has one
+    pub fn first_visible(
+        &self,
+        from: Point,
+        is_visible: impl Fn(&T) -> bool,
+    ) -> Vec<(Direction, Point)> {
+        Direction::VALUES_8
+            .into_iter()
+            .filter_map(|direction| {
+                let (point, _) = self.cast(from, direction.offset(), &is_visible)?;
+                Some((direction, point))
+            })
+            .collect()
+    }
+
+    /// Whether no cell strictly between `from` and `to` on their Bresenham line is `blocks`, with
+    /// cells outside the grid blocking the view
+    pub fn can_see(&self, from: Point, to: Point, blocks: impl Fn(&T) -> bool) -> bool {
+        let line = bresenham(to_i64(&from), to_i64(&to));
+        if line.len() <= 2 {
+            return true;
+        }
+        line[1..line.len() - 1].iter().all(|&(line, column)| {
+            self.get((line as usize, column as usize))
+                .is_some_and(|cell| !blocks(cell))
+        })
+    }
+}
+
+impl MultiLineParser {
+    /// The characters from `from` (excluded) along `step` until leaving the lines, without moving
+    /// the cursor
+    pub fn ray(&self, from: Point, step: PointI64) -> impl Iterator<Item = (Point, char)> + '_ {
+        ray(from, step).map_while(|point| self.get(point).map(|c| (point, c)))
+    }
+
+    /// The first character matching `is_visible` in each of the 8 directions that has one
+    pub fn first_visible(
+        &self,
+        from: Point,
+        is_visible: impl Fn(char) -> bool,
+    ) -> Vec<(Direction, Point)> {
+        Direction::VALUES_8
+            .into_iter()
+            .filter_map(|direction| {
+                let (point, _) = self
+                    .ray(from, direction.offset())
+                    .find(|&(_, c)| is_visible(c))?;
+                Some((direction, point))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray() {
+        assert_eq!(
+            ray((4, 1), (-2, 1)).collect::<Vec<_>>(),
+            vec![(2, 2), (0, 3)]
+        );
+        let grid = Grid::from(&MultiLineParser::new("abcd\nefgh\nijkl"));
+        assert_eq!(
+            grid.ray((0, 0), (1, 2)).collect::<Vec<_>>(),
+            vec![((1, 2), &'g')]
+        );
+        assert_eq!(
+            grid.cast((2, 3), (0, -1), |&c| c < 'k'),
+            Some(((2, 1), &'j'))
+        );
+        assert_eq!(grid.cast((2, 3), (-1, 0), |&c| c == 'a'), None);
+        assert_eq!(ray((1, 1), (0, 0)).next(), None);
+    }
+
+    #[test]
+    fn test_bresenham() {
+        assert_eq!(bresenham((0, 0), (0, 0)), vec![(0, 0)]);
+        assert_eq!(
+            bresenham((0, 0), (2, 4)),
+            vec![(0, 0), (1, 1), (1, 2), (2, 3), (2, 4)]
+        );
+        assert_eq!(
+            bresenham((3, 1), (0, 0)),
+            vec![(3, 1), (2, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_visibility() {
+        let parser = MultiLineParser::new("#.#..\n.....\n#.L.#\n.....\n..#..");
+        assert_eq!(
+            parser.first_visible((2, 2), |c| c == '#'),
+            vec![
+                (Direction::Right, (2, 4)),
+                (Direction::Down, (4, 2)),
+                (Direction::Left, (2, 0)),
+                (Direction::LeftUp, (0, 0)),
+                (Direction::Up, (0, 2))
+            ]
+        );
+        let grid = Grid::from(&parser);
+        assert!(grid.can_see((0, 0), (4, 2), |&c| c == '#'));
+        assert!(!grid.can_see((2, 0), (2, 4), |&c| c == 'L'));
+        assert!(grid.can_see((2, 2), (2, 2), |&c| c == 'L'));
+        assert!(grid.can_see((2, 2), (2, 3), |&c| c == 'L'));
+        assert!(!grid.can_see((0, 0), (0, 9), |&c| c == 'L'));
+        assert_eq!(grid.first_visible((2, 2), |&c| c == '#').len(), 5);
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{Direction, Grid, MultiLineParser, Nearable, Point};
+use std::collections::VecDeque;
+
+/// Which neighbours of a cell count as touching it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(&self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => &Direction::VALUES_4,
+            Connectivity::Eight => &Direction::VALUES_8,
+        }
+    }
+
+    /// The connectivity the background has to use so regions and holes do not leak into each
+    /// other through diagonals
+    fn complement(&self) -> Connectivity {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// The connected regions of a grid, numbered in the order their first cell is found row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    connectivity: Connectivity,
+    labels: Grid<Option<usize>>,
+    points: Vec<Vec<Point>>,
+}
+
+impl Regions {
+    /// The label of every cell, `None` for cells left out of all regions
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn label(&self, point: Point) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn get(&self, label: usize) -> Option<Region<'_>> {
+        self.points.get(label).map(|points| Region {
+            label,
+            points,
+            labels: &self.labels,
+            connectivity: self.connectivity,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Region<'_>> {
+        (0..self.len()).filter_map(|label| self.get(label))
+    }
+}
+
+/// One region of `Regions`, with its shape measurements
+#[derive(Debug, Clone, Copy)]
+pub struct Region<'a> {
+    label: usize,
+    points: &'a [Point],
+    labels: &'a Grid<Option<usize>>,
+    connectivity: Connectivity,
+}
+
+impl<'a> Region<'a> {
+    pub fn label(&self) -> usize {
+        self.label
+    }
+
+    /// The points of the region, the first one found first
+    pub fn points(&self) -> &'a [Point] {
+        self.points
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.labels.get(point) == Some(&Some(self.label))
+    }
+
+    fn contains_towards(&self, point: Point, direction: &Direction) -> bool {
+        point
+            .checked_with_direction(direction)
+            .is_some_and(|next| self.contains(next))
+    }
+
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// The number of cell edges between the region and anything else, the grid edge included
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .map(|&point| {
+                Direction::VALUES_4
+                    .iter()
+                    .filter(|direction| !self.contains_towards(point, direction))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The number of straight fences around the region, holes included, counted as corners
+    pub fn sides(&self) -> usize {
+        const CORNERS: [(Direction, Direction, Direction); 4] = [
+            (Direction::Up, Direction::Right, Direction::UpRight),
+            (Direction::Right, Direction::Down, Direction::RightDown),
+            (Direction::Down, Direction::Left, Direction::DownLeft),
+            (Direction::Left, Direction::Up, Direction::LeftUp),
+        ];
+        self.points
+            .iter()
+            .map(|&point| {
+                CORNERS
+                    .iter()
+                    .filter(|(a, b, diagonal)| {
+                        let a = self.contains_towards(point, a);
+                        let b = self.contains_towards(point, b);
+                        let outer = !a && !b;
+                        let inner = a && b && !self.contains_towards(point, diagonal);
+                        outer || inner
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding the region
+    pub fn bounding_box(&self) -> (Point, Point) {
+        self.points
+            .iter()
+            .skip(1)
+            .fold((self.points[0], self.points[0]), |(from, to), point| {
+                (
+                    (from.0.min(point.0), from.1.min(point.1)),
+                    (to.0.max(point.0), to.1.max(point.1)),
+                )
+            })
+    }
+
+    /// The groups of cells fully enclosed by the region.
+    ///
+    /// Holes connect through the connectivity the region did not use, so a 4-connected region
+    /// only encloses cells that are also cut off diagonally.
+    pub fn holes(&self) -> Vec<Vec<Point>> {
+        let (from, to) = self.bounding_box();
+        // One cell of margin on each side so the outside is a single connected area
+        let (height, width) = (to.0 - from.0 + 3, to.1 - from.1 + 3);
+        let inside = |(line, column): Point| {
+            line > 0
+                && column > 0
+                && line < height - 1
+                && column < width - 1
+                && self.contains((from.0 + line - 1, from.1 + column - 1))
+        };
+        let mut seen = Grid::from_fn(height, width, inside);
+        let mut holes = vec![];
+        for start in (0..height * width).map(|i| (i / width, i % width)) {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut area = vec![];
+            let mut touches_outside = false;
+            let mut queue = VecDeque::from([start]);
+            while let Some(point) = queue.pop_front() {
+                touches_outside |=
+                    point.0 == 0 || point.1 == 0 || point.0 == height - 1 || point.1 == width - 1;
+                area.push(point);
+                for direction in self.connectivity.complement().directions() {
+                    if let Some(next) = seen.step(point, direction) {
+                        if !seen[next] {
+                            seen[next] = true;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+            if !touches_outside {
+                let to_grid = |(line, column): Point| (from.0 + line - 1, from.1 + column - 1);
+                holes.push(area.into_iter().map(to_grid).collect());
+            }
+        }
+        holes
+    }
+}
+
+impl<T> Grid<T> {
+    /// Labels every cell, joining neighbours for which `same` holds
+    pub fn regions(&self, connectivity: Connectivity, same: impl Fn(&T, &T) -> bool) -> Regions {
+        self.regions_where(connectivity, |_| true, same)
+    }
+
+    /// Labels only the cells matching `include`, joining included neighbours for which `same`
+    /// holds
+    pub fn regions_where(
+        &self,
+        connectivity: Connectivity,
+        include: impl Fn(&T) -> bool,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let mut labels = Grid::new(self.height(), self.width(), None);
+        let mut points = vec![];
+        for (start, cell) in self.iter() {
+            if labels[start].is_some() || !include(cell) {
+                continue;
+            }
+            let label = points.len();
+            labels[start] = Some(label);
+            let mut region = vec![];
+            let mut queue = VecDeque::from([start]);
+            while let Some(point) = queue.pop_front() {
+                region.push(point);
+                for direction in connectivity.directions() {
+                    let Some(next) = self.step(point, direction) else {
+                        continue;
+                    };
+                    if labels[next].is_none()
+                        && include(&self[next])
+                        && same(&self[point], &self[next])
+                    {
+                        labels[next] = Some(label);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            points.push(region);
+        }
+        Regions {
+            connectivity,
+            labels,
+            points,
+        }
+    }
+}
+
+impl MultiLineParser {
+    /// Groups neighbouring cells holding the same character. Cells past the end of a shorter line
+    /// belong to no region.
+    pub fn regions(&self, connectivity: Connectivity) -> Regions {
+        let height = self.len();
+        let width = self.lines().iter().map(|l| l.len()).max().unwrap_or(0);
+        Grid::from_fn(height, width, |point| self.get(point)).regions_where(
+            connectivity,
+            Option::is_some,
+            |a, b| a == b,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::from(&MultiLineParser::new(text))
+    }
+
+    #[test]
+    fn test_labels() {
+        let regions = MultiLineParser::new("AAB\nBAB\nBBA").regions(Connectivity::Four);
+        assert_eq!(regions.len(), 4);
+        assert_eq!(regions.label((0, 2)), Some(1));
+        assert_eq!(regions.label((2, 2)), Some(3));
+        let regions = MultiLineParser::new("AAB\nBAB\nBBA").regions(Connectivity::Eight);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.label((2, 2)), Some(0));
+        assert_eq!(regions.get(0).unwrap().area(), 4);
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        let regions = MultiLineParser::new("aaa\nb").regions(Connectivity::Four);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.label((1, 1)), None);
+    }
+
+    #[test]
+    fn test_regions_where() {
+        let rocks = grid("#..#\n#.##\n....");
+        let regions = rocks.regions_where(Connectivity::Four, |&c| c == '#', |_, _| true);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.label((0, 1)), None);
+        let heights = Grid::from_parser(&MultiLineParser::new("123\n589"), |c| {
+            c.to_digit(10).unwrap()
+        });
+        let slopes = heights.regions(Connectivity::Four, |a, b| a.abs_diff(*b) == 1);
+        assert_eq!(slopes.len(), 3);
+        assert_eq!(slopes.get(1).unwrap().points(), &[(1, 0)]);
+    }
+
+    #[test]
+    fn test_measurements() {
+        let regions = MultiLineParser::new("AAAA\nBBCD\nBBCC\nEEEC").regions(Connectivity::Four);
+        let measured = regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.sides()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            measured,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions.get(2).unwrap().bounding_box(), ((1, 2), (3, 3)));
+    }
+
+    #[test]
+    fn test_holes() {
+        let parser = MultiLineParser::new("OOOOO\nOXOXO\nOOOOO\nOXOOO\nOOO.O");
+        let regions = parser.regions(Connectivity::Four);
+        let outer = regions.get(0).unwrap();
+        assert_eq!(
+            outer.holes(),
+            vec![vec![(1, 1)], vec![(1, 3)], vec![(3, 1)]]
+        );
+        let ring = grid("###\n#.#\n##.");
+        let four = ring.regions_where(Connectivity::Four, |&c| c == '#', |_, _| true);
+        assert!(four.get(0).unwrap().holes().is_empty());
+        let eight = ring.regions_where(Connectivity::Eight, |&c| c == '#', |_, _| true);
+        assert_eq!(eight.get(0).unwrap().holes(), vec![vec![(1, 1)]]);
+    }
+}
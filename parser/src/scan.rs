@@ -0,0 +1,291 @@
+//! Scanf-style extraction of values from lines following a fixed template.
+//!
+//! Every `{}` in a template captures the text up to the next literal part of the template, or up
+//! to the end of the line for a trailing `{}`. Literal braces are written `{{` and `}}`.
+//!
+//! Errors report the input line, counting the blank lines `MultiLineParser::new` skips, and the
+//! column within the trimmed line. A section taken from `Sections` counts its lines from 1.
+//!
+//! ```
+//! use parser::MultiLineParser;
+//!
+//! let parser = MultiLineParser::new("p=0,4 v=3,-3\np=6,3 v=-1,-3");
+//! let robots = parser.scan::<(usize, usize, i64, i64)>("p={},{} v={},{}");
+//! assert_eq!(robots, Ok(vec![(0, 4, 3, -3), (6, 3, -1, -3)]));
+//! ```
+use crate::combinator::{literal, Combinator};
+use crate::{ParseError, Parser};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Capture,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pieces: Vec<Piece>,
+}
+
+/// The raw text captured for each `{}` of a template, converted to typed values on demand
+pub struct Captures {
+    parser: Parser,
+    values: Vec<(String, usize)>,
+    next: usize,
+}
+
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &mut Captures) -> Result<Self, ParseError>;
+}
+
+impl Template {
+    /// # Panics
+    ///
+    /// Panics on two adjacent `{}`, as nothing tells where the first capture ends
+    pub fn new(template: &str) -> Self {
+        let mut pieces = vec![];
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('}')) => {
+                    chars.next();
+                    if !text.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut text)));
+                    }
+                    assert!(
+                        pieces.last() != Some(&Piece::Capture),
+                        "adjacent {{}} in template \"{}\"",
+                        template
+                    );
+                    pieces.push(Piece::Capture);
+                }
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    text.push(c);
+                }
+                _ => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            pieces.push(Piece::Literal(text));
+        }
+        Self { pieces }
+    }
+
+    /// Matches the remaining characters of `parser` against the template
+    pub fn captures(&self, parser: &Parser) -> Result<Captures, ParseError> {
+        let mut parser = parser.clone();
+        let mut values = vec![];
+        for (i, piece) in self.pieces.iter().enumerate() {
+            match piece {
+                Piece::Literal(text) => {
+                    literal(text).parse(&mut parser)?;
+                }
+                Piece::Capture => {
+                    let start = parser.cursor();
+                    let end = match self.pieces.get(i + 1) {
+                        // fall back to the first character so the literal reports where it differs
+                        Some(Piece::Literal(text)) => {
+                            find(&parser, text, start + 1).or_else(|| {
+                                find(
+                                    &parser,
+                                    &text[..text.chars().next().unwrap().len_utf8()],
+                                    start + 1,
+                                )
+                            })
+                        }
+                        _ => Some(parser.len()),
+                    };
+                    let Some(end) = end.filter(|&end| end > start) else {
+                        return Err(ParseError::at(&parser, "value"));
+                    };
+                    values.push((parser.as_chars()[start..end].iter().collect(), start));
+                    parser.go_to(end);
+                }
+            }
+        }
+        if !parser.is_done() {
+            return Err(ParseError::at(&parser, "end of line"));
+        }
+        parser.reset();
+        Ok(Captures {
+            parser,
+            values,
+            next: 0,
+        })
+    }
+
+    pub fn scan<T: FromCaptures>(&self, parser: &Parser) -> Result<T, ParseError> {
+        T::from_captures(&mut self.captures(parser)?)
+    }
+}
+
+/// Finds the first position at or after `from` where `target` starts
+fn find(parser: &Parser, target: &str, from: usize) -> Option<usize> {
+    let target = target.chars().collect::<Vec<char>>();
+    let chars = parser.as_chars();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&target))
+}
+
+impl Captures {
+    /// Wraps text captured from `parser` along with the cursor each capture starts at
+    pub(crate) fn from_values(parser: Parser, values: Vec<(String, usize)>) -> Self {
+        Self {
+            parser,
+            values,
+            next: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Converts the next capture, reporting the column it started at when it does not parse
+    pub fn next_value<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let Some((value, start)) = self.values.get(self.next) else {
+            self.parser.go_to(self.parser.len());
+            return Err(ParseError::at(&self.parser, "another {} in the template"));
+        };
+        self.next += 1;
+        value.parse().map_err(|_| {
+            self.parser.go_to(*start);
+            ParseError::at(&self.parser, std::any::type_name::<T>())
+                .with_found(format!("\"{}\"", value))
+        })
+    }
+
+    /// Fails at the first capture left unconverted, for types taking a fixed number of values
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        let Some((value, start)) = self.values.get(self.next) else {
+            return Ok(());
+        };
+        self.parser.go_to(*start);
+        Err(
+            ParseError::at(&self.parser, "no more captures").with_found(format!(
+                "\"{}\", capture {} of {}",
+                value,
+                self.next + 1,
+                self.len()
+            )),
+        )
+    }
+}
+
+impl<T: FromStr> FromCaptures for Vec<T> {
+    fn from_captures(captures: &mut Captures) -> Result<Self, ParseError> {
+        (0..captures.len() - captures.next)
+            .map(|_| captures.next_value())
+            .collect()
+    }
+}
+
+macro_rules! impl_from_captures {
+    ($($name:ident),+) => {
+        impl<$($name: FromStr),+> FromCaptures for ($($name,)+) {
+            fn from_captures(captures: &mut Captures) -> Result<Self, ParseError> {
+                let values = ($(captures.next_value::<$name>()?,)+);
+                captures.finish()?;
+                Ok(values)
+            }
+        }
+    };
+}
+
+impl_from_captures!(A);
+impl_from_captures!(A, B);
+impl_from_captures!(A, B, C);
+impl_from_captures!(A, B, C, D);
+impl_from_captures!(A, B, C, D, E);
+impl_from_captures!(A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiLineParser;
+
+    #[test]
+    fn test_scan_line() {
+        let template = Template::new("Button A: X+{}, Y+{}");
+        let parser = Parser::new("Button A: X+94, Y+34");
+        assert_eq!(template.scan::<(u64, u64)>(&parser), Ok((94, 34)));
+    }
+
+    #[test]
+    fn test_scan_strings() {
+        let template = Template::new("{} -> {}");
+        let parser = Parser::new("x00 AND y00 -> z00");
+        assert_eq!(
+            template.scan::<Vec<String>>(&parser),
+            Ok(vec!["x00 AND y00".to_string(), "z00".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        let template = Template::new("{{{}}} -> {}");
+        let parser = Parser::new("{a} -> b}}");
+        assert_eq!(
+            template.scan::<(String, String)>(&parser),
+            Ok(("a".to_string(), "b}}".to_string()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent {} in template")]
+    fn test_adjacent_captures() {
+        Template::new("p={}{}");
+    }
+
+    #[test]
+    fn test_literal_mismatch() {
+        let parser = MultiLineParser::new("p=0,4 v=3,-3\np=6,3 w=-1,-3");
+        let error = parser
+            .scan::<(i64, i64, i64, i64)>("p={},{} v={},{}")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.found, "'w'");
+    }
+
+    #[test]
+    fn test_value_mismatch() {
+        let parser = MultiLineParser::new("p=0,4 v=3,-3\np=6,3 v=a,-3");
+        let error = parser
+            .scan::<(i64, i64, i64, i64)>("p={},{} v={},{}")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.found, "\"a\"");
+    }
+
+    #[test]
+    fn test_trailing_text() {
+        let parser = Parser::new("Register A: 729 extra");
+        let error = Template::new("Register A: {} ")
+            .scan::<(u64,)>(&parser)
+            .unwrap_err();
+        assert_eq!(error.expected, "end of line");
+    }
+
+    #[test]
+    fn test_capture_count() {
+        let parser = Parser::new("p=0,4 v=3,-3");
+        let error = Template::new("p={},{} v={},{}")
+            .scan::<(i64,)>(&parser)
+            .unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected no more captures, found \"4\", capture 2 of 4"
+        );
+        let error = Template::new("p={},{}")
+            .scan::<(i64, i64, i64)>(&Parser::new("p=0,4"))
+            .unwrap_err();
+        assert_eq!(error.expected, "another {} in the template");
+    }
+}
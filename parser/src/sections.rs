@@ -0,0 +1,155 @@
+use crate::{MultiLineParser, Parser};
+
+/// The blocks of an input separated by blank lines
+///
+/// ```
+/// use parser::{MultiLineParser, Parser, Sections};
+///
+/// let sections = Sections::new("Register A: 729\nRegister B: 0\n\nProgram: 0,1,5\n");
+/// let (registers, program) = sections.pair::<MultiLineParser, Parser>();
+/// assert_eq!(registers.len(), 2);
+/// assert_eq!(program.to_string(), "Program: 0,1,5");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sections<'a> {
+    sections: Vec<&'a str>,
+}
+
+/// Conversion of the raw text of a section into something to parse it with
+pub trait FromSection<'a> {
+    fn from_section(section: &'a str) -> Self;
+}
+
+impl<'a> FromSection<'a> for &'a str {
+    fn from_section(section: &'a str) -> Self {
+        section
+    }
+}
+
+impl FromSection<'_> for String {
+    fn from_section(section: &str) -> Self {
+        section.to_string()
+    }
+}
+
+impl FromSection<'_> for Parser {
+    fn from_section(section: &str) -> Self {
+        Parser::new(section)
+    }
+}
+
+impl FromSection<'_> for MultiLineParser {
+    fn from_section(section: &str) -> Self {
+        MultiLineParser::new(section)
+    }
+}
+
+impl<'a> Sections<'a> {
+    /// Splits `input` on lines that are empty or only whitespace, ignoring leading and trailing
+    /// blank lines
+    pub fn new(input: &'a str) -> Self {
+        let mut sections = vec![];
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in input.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if let Some(start) = start.take() {
+                    sections.push(&input[start..end]);
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + line.trim_end_matches(['\n', '\r']).len();
+            }
+            offset += line.len();
+        }
+        if let Some(start) = start {
+            sections.push(&input[start..end]);
+        }
+        Self { sections }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        self.sections.get(index).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.sections.iter().copied()
+    }
+
+    pub fn parser(&self, index: usize) -> Parser {
+        Parser::new(self.sections[index])
+    }
+
+    pub fn multi_line_parser(&self, index: usize) -> MultiLineParser {
+        MultiLineParser::new(self.sections[index])
+    }
+
+    /// Converts every section the same way, e.g. one `MultiLineParser` per block
+    pub fn all<T: FromSection<'a>>(&self) -> Vec<T> {
+        self.iter().map(T::from_section).collect()
+    }
+
+    /// Destructures an input made of exactly two sections
+    pub fn pair<A: FromSection<'a>, B: FromSection<'a>>(&self) -> (A, B) {
+        assert_eq!(self.len(), 2, "expected 2 sections, found {}", self.len());
+        (
+            A::from_section(self.sections[0]),
+            B::from_section(self.sections[1]),
+        )
+    }
+
+    /// Destructures the common shape of a single header section followed by the body sections
+    pub fn header_body<H: FromSection<'a>, B: FromSection<'a>>(&self) -> (H, Vec<B>) {
+        let (header, body) = self
+            .sections
+            .split_first()
+            .expect("expected a header section");
+        (
+            H::from_section(header),
+            body.iter().copied().map(B::from_section).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_on_blank_lines() {
+        let sections = Sections::new("\n47|53\n97|13\n\n\n75,47\n  \n61,13,29\n\n");
+        assert_eq!(
+            sections.all::<&str>(),
+            vec!["47|53\n97|13", "75,47", "61,13,29"]
+        );
+        assert_eq!(Sections::new("\n\n").len(), 0);
+        assert_eq!(
+            Sections::new("a\r\n\r\nb\r\n").all::<&str>(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn test_pair() {
+        let (ranges, ids) = Sections::new("3-5\n10-14\n\n1\n5").pair::<MultiLineParser, String>();
+        assert_eq!(ranges.split_to_numbers("-"), vec![vec![3, 5], vec![10, 14]]);
+        assert_eq!(ids, "1\n5");
+    }
+
+    #[test]
+    fn test_header_body() {
+        let sections = Sections::new("r, wr, b\n\nbrwrr\n\n0:\n###");
+        let (header, body) = sections.header_body::<Parser, MultiLineParser>();
+        assert_eq!(header.split_to_strings(", "), vec!["r", "wr", "b"]);
+        assert_eq!(body.iter().map(|b| b.len()).collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
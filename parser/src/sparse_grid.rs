@@ -0,0 +1,189 @@
+use crate::{Direction, MultiLineParser, PointI64};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+/// An unbounded grid keyed by signed `(line, column)` points, storing only the cells that were set.
+///
+/// Every other cell reads as the default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<PointI64, T>,
+    default: T,
+    bounds: Option<(PointI64, PointI64)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: PointI64) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: PointI64) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    pub fn insert(&mut self, point: PointI64, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                (min.0.min(point.0), min.1.min(point.1)),
+                (max.0.max(point.0), max.1.max(point.1)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: PointI64) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounds {
+            if point.0 == min.0 || point.0 == max.0 || point.1 == min.1 || point.1 == max.1 {
+                self.bounds = bounds(self.cells.keys());
+            }
+        }
+        Some(value)
+    }
+
+    /// The smallest and largest line and column of the cells that were set
+    pub fn bounds(&self) -> Option<(PointI64, PointI64)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PointI64, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn neighbors4(&self, point: PointI64) -> impl Iterator<Item = (PointI64, &T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_4)
+    }
+
+    pub fn neighbors8(&self, point: PointI64) -> impl Iterator<Item = (PointI64, &T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_8)
+    }
+
+    fn neighbors(
+        &self,
+        point: PointI64,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (PointI64, &T)> + '_ {
+        directions.iter().map(move |direction| {
+            let (line, column) = direction.offset();
+            let next = (point.0 + line, point.1 + column);
+            (next, self.get(next))
+        })
+    }
+
+    /// Groups the cells that were set into 4-connected regions
+    pub fn regions(&self) -> Vec<Vec<PointI64>> {
+        let mut seen = HashSet::new();
+        let mut regions = vec![];
+        for &start in self.cells.keys() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut region = vec![];
+            let mut queue = VecDeque::from([start]);
+            while let Some(point) = queue.pop_front() {
+                region.push(point);
+                for (next, _) in self.neighbors4(point) {
+                    if self.contains(next) && seen.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+        regions
+    }
+
+    /// Renders the bounding box of the cells that were set, one character per cell
+    pub fn to_parser(&self, f: impl Fn(&T) -> char) -> MultiLineParser {
+        MultiLineParser::new_preserving(&self.render(|cell| f(cell).to_string()), ' ')
+    }
+
+    fn render(&self, f: impl Fn(&T) -> String) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        let mut text = String::new();
+        for line in min.0..=max.0 {
+            for column in min.1..=max.1 {
+                text.push_str(&f(self.get((line, column))));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+fn bounds<'a>(points: impl Iterator<Item = &'a PointI64>) -> Option<(PointI64, PointI64)> {
+    points.fold(None, |bounds, &point| {
+        Some(match bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                (point.0.min(min.0), point.1.min(min.1)),
+                (point.0.max(max.0), point.1.max(max.1)),
+            ),
+        })
+    })
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|cell| cell.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new('.');
+        grid.insert((-2, 3), '#');
+        grid.insert((1, -1), '#');
+        grid.insert((0, 0), 'S');
+        assert_eq!(grid.bounds(), Some(((-2, -1), (1, 3))));
+        assert_eq!(grid.get((5, 5)), &'.');
+        grid.remove((-2, 3));
+        assert_eq!(grid.bounds(), Some(((0, -1), (1, 0))));
+        assert_eq!(grid.to_string(), ".S\n#.\n");
+    }
+
+    #[test]
+    fn test_neighbors_and_regions() {
+        let mut grid = SparseGrid::new(0);
+        for point in [(0, 0), (0, 1), (1, 1), (3, 3), (-1, -1)] {
+            grid.insert(point, 1);
+        }
+        assert_eq!(grid.neighbors8((0, 0)).map(|(_, v)| v).sum::<i32>(), 3);
+        let mut sizes = grid.regions().iter().map(Vec::len).collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 3]);
+    }
+
+    #[test]
+    fn test_to_parser() {
+        let mut grid = SparseGrid::new(false);
+        grid.insert((-1, -1), true);
+        grid.insert((0, 1), true);
+        let parser = grid.to_parser(|&v| if v { '#' } else { ' ' });
+        assert_eq!(parser.to_string(), "#  \n  #\n");
+        assert_eq!(SparseGrid::<u8>::new(0).to_string(), "");
+    }
+}
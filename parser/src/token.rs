@@ -0,0 +1,259 @@
+//! Tokenizing of instruction-style inputs and scanning of corrupted text for patterns.
+//!
+//! ```
+//! use parser::token::{find_all, TokenKind, Tokenizer};
+//!
+//! let tokens = Tokenizer::new().keywords(&["bst", "out"]).tokenize("bst 4\nout -2");
+//! assert_eq!(tokens[0].kind, TokenKind::Keyword);
+//! assert_eq!(tokens[3].value::<i64>(), Some(-2));
+//!
+//! let found = find_all("xmul(2,4)%&mul[3,7]!mul(11,8)", "mul({n},{n})");
+//! assert_eq!(found.len(), 2);
+//! assert_eq!(found[1].numbers, vec![11, 8]);
+//! ```
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Identifier,
+    Integer,
+    Punctuation,
+    Keyword,
+}
+
+/// A piece of the input, with `span` the byte range it covers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+impl Token<'_> {
+    pub fn value<T: FromStr>(&self) -> Option<T> {
+        self.text.parse().ok()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Tokenizer {
+    keywords: Vec<String>,
+    punctuation: Vec<String>,
+    unsigned: bool,
+}
+
+impl Tokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Words read as `Keyword` instead of `Identifier`, which may contain punctuation like `don't`
+    pub fn keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords = sorted_longest_first(keywords);
+        self
+    }
+
+    /// Multi-character punctuation such as `->` kept together, other symbols are single characters
+    pub fn punctuation(mut self, punctuation: &[&str]) -> Self {
+        self.punctuation = sorted_longest_first(punctuation);
+        self
+    }
+
+    /// Reads `-` and `+` as punctuation even when a digit follows them
+    pub fn unsigned(mut self) -> Self {
+        self.unsigned = true;
+        self
+    }
+
+    /// Splits `input` into tokens, skipping whitespace
+    pub fn tokenize<'a>(&self, input: &'a str) -> Vec<Token<'a>> {
+        let mut tokens = vec![];
+        let mut start = 0;
+        while let Some(c) = input[start..].chars().next() {
+            if c.is_whitespace() {
+                start += c.len_utf8();
+                continue;
+            }
+            let (kind, len) = self.next_token(input, start);
+            tokens.push(Token {
+                kind,
+                text: &input[start..start + len],
+                span: start..start + len,
+            });
+            start += len;
+        }
+        tokens
+    }
+
+    fn next_token(&self, input: &str, start: usize) -> (TokenKind, usize) {
+        let rest = &input[start..];
+        if let Some(keyword) = self.keywords.iter().find(|k| {
+            rest.starts_with(k.as_str())
+                && !(k.ends_with(is_identifier) && rest[k.len()..].starts_with(is_identifier))
+        }) {
+            return (TokenKind::Keyword, keyword.len());
+        }
+        let previous = input[..start].chars().next_back();
+        let signed = !self.unsigned
+            && rest.starts_with(['-', '+'])
+            && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+            && !previous.is_some_and(is_identifier);
+        if signed || rest.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits = rest[1..].find(|c: char| !c.is_ascii_digit());
+            return (TokenKind::Integer, digits.map_or(rest.len(), |d| d + 1));
+        }
+        if rest.starts_with(is_identifier) {
+            let len = rest.find(|c| !is_identifier(c)).unwrap_or(rest.len());
+            return (TokenKind::Identifier, len);
+        }
+        let len = self
+            .punctuation
+            .iter()
+            .find(|p| rest.starts_with(p.as_str()))
+            .map_or_else(|| rest.chars().next().unwrap().len_utf8(), String::len);
+        (TokenKind::Punctuation, len)
+    }
+}
+
+fn sorted_longest_first(words: &[&str]) -> Vec<String> {
+    let mut words = words.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+    words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+    words
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// An occurrence of one of the patterns given to [`find_all_of`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub pattern: usize,
+    pub text: &'a str,
+    pub span: Range<usize>,
+    pub numbers: Vec<i64>,
+}
+
+/// Finds the non-overlapping occurrences of `pattern` from left to right, where every `{n}` in the
+/// pattern matches a run of digits and everything else must match exactly. Unlike the `{}` of a
+/// [`scan`](crate::scan) template, `{n}` never matches anything but digits.
+pub fn find_all<'a>(text: &'a str, pattern: &str) -> Vec<Match<'a>> {
+    find_all_of(text, &[pattern])
+}
+
+/// Like [`find_all`], but with several patterns, the first one matching at a position winning
+pub fn find_all_of<'a>(text: &'a str, patterns: &[&str]) -> Vec<Match<'a>> {
+    let patterns = patterns
+        .iter()
+        .map(|p| p.split("{n}").collect::<Vec<&str>>())
+        .collect::<Vec<_>>();
+    let mut matches = vec![];
+    let mut start = 0;
+    while start < text.len() {
+        let width = text[start..].chars().next().unwrap().len_utf8();
+        let found = patterns.iter().enumerate().find_map(|(i, literals)| {
+            match_at(text, start, literals).map(|(end, numbers)| Match {
+                pattern: i,
+                text: &text[start..end],
+                span: start..end,
+                numbers,
+            })
+        });
+        match found {
+            Some(found) => {
+                start = found.span.end.max(start + width);
+                matches.push(found);
+            }
+            None => start += width,
+        }
+    }
+    matches
+}
+
+/// Matches the literal parts of a pattern with digits between each of them
+fn match_at(text: &str, mut cursor: usize, literals: &[&str]) -> Option<(usize, Vec<i64>)> {
+    let mut numbers = vec![];
+    for (i, literal) in literals.iter().enumerate() {
+        if i > 0 {
+            let rest = &text[cursor..];
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits == 0 {
+                return None;
+            }
+            numbers.push(rest[..digits].parse().ok()?);
+            cursor += digits;
+        }
+        if !text[cursor..].starts_with(literal) {
+            return None;
+        }
+        cursor += literal.len();
+    }
+    Some((cursor, numbers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds<'a>(tokens: &[Token<'a>]) -> Vec<(TokenKind, &'a str)> {
+        tokens.iter().map(|t| (t.kind, t.text)).collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        use TokenKind::*;
+        let tokenizer = Tokenizer::new()
+            .keywords(&["do", "don't"])
+            .punctuation(&["->"]);
+        let tokens = tokenizer.tokenize("don't() do_not x00 -> z-1 -3");
+        assert_eq!(
+            kinds(&tokens),
+            vec![
+                (Keyword, "don't"),
+                (Punctuation, "("),
+                (Punctuation, ")"),
+                (Identifier, "do_not"),
+                (Identifier, "x00"),
+                (Punctuation, "->"),
+                (Identifier, "z"),
+                (Punctuation, "-"),
+                (Integer, "1"),
+                (Integer, "-3"),
+            ]
+        );
+        assert_eq!(tokens[3].span, 8..14);
+    }
+
+    #[test]
+    fn test_tokenize_unsigned() {
+        let tokens = Tokenizer::new().unsigned().tokenize("3-5");
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[2].value::<u8>(), Some(5));
+    }
+
+    #[test]
+    fn test_find_all_of() {
+        let text = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let found = find_all_of(text, &["mul({n},{n})", "do()", "don't()"]);
+        assert_eq!(
+            found
+                .iter()
+                .map(|m| (m.pattern, m.numbers.as_slice()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, [2, 4].as_slice()),
+                (2, &[]),
+                (0, &[5, 5]),
+                (0, &[11, 8]),
+                (1, &[]),
+                (0, &[8, 5]),
+            ]
+        );
+        assert_eq!(found[0].span, 1..9);
+        assert!(find_all("mul(,1)mul(1 ,2)mul(-1,2)", "mul({n},{n})").is_empty());
+        assert_eq!(find_all("aé", "").len(), 2);
+    }
+}
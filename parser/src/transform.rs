@@ -0,0 +1,269 @@
+use crate::{Grid, MultiLineParser, Parser, Point};
+
+impl<T: Clone> Grid<T> {
+    /// Rotates a quarter turn clockwise, so the first column becomes the first line reversed
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        Grid::from_fn(self.width(), height, |(line, column)| {
+            self[(height - 1 - column, line)].clone()
+        })
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width();
+        Grid::from_fn(width, self.height(), |(line, column)| {
+            self[(column, width - 1 - line)].clone()
+        })
+    }
+
+    /// Mirrors left to right
+    pub fn flip_h(&self) -> Self {
+        let width = self.width();
+        Grid::from_fn(self.height(), width, |(line, column)| {
+            self[(line, width - 1 - column)].clone()
+        })
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_v(&self) -> Self {
+        let height = self.height();
+        Grid::from_fn(height, self.width(), |(line, column)| {
+            self[(height - 1 - line, column)].clone()
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.width(), self.height(), |(line, column)| {
+            self[(column, line)].clone()
+        })
+    }
+
+    /// The four rotations followed by the four rotations of the mirrored grid
+    pub fn symmetries(&self) -> [Self; 8] {
+        let flipped = self.flip_h();
+        [
+            self.clone(),
+            self.rotate_cw(),
+            self.rotate_cw().rotate_cw(),
+            self.rotate_ccw(),
+            flipped.clone(),
+            flipped.rotate_cw(),
+            flipped.rotate_cw().rotate_cw(),
+            flipped.rotate_ccw(),
+        ]
+    }
+
+    /// The cells between the corners `from` and `to`, both included and clipped to the grid
+    pub fn subgrid(&self, from: Point, to: Point) -> Self {
+        if self.height() == 0 || self.width() == 0 {
+            return Grid::empty();
+        }
+        let top_left = (from.0.min(to.0), from.1.min(to.1));
+        let bottom_right = (
+            from.0.max(to.0).min(self.height().saturating_sub(1)),
+            from.1.max(to.1).min(self.width().saturating_sub(1)),
+        );
+        let height = (bottom_right.0 + 1).saturating_sub(top_left.0);
+        let width = (bottom_right.1 + 1).saturating_sub(top_left.1);
+        Grid::from_fn(height, width, |(line, column)| {
+            self[(top_left.0 + line, top_left.1 + column)].clone()
+        })
+    }
+
+    /// Copies `other` with its top left corner at `at`, dropping what falls outside
+    pub fn paste(&mut self, at: Point, other: &Grid<T>) {
+        for ((line, column), cell) in other.iter() {
+            if let Some(target) = self.get_mut((at.0 + line, at.1 + column)) {
+                *target = cell.clone();
+            }
+        }
+    }
+
+    /// The smallest subgrid holding every cell that is not `is_background`
+    pub fn crop_to_content(&self, is_background: impl Fn(&T) -> bool) -> Self {
+        let content = self
+            .iter()
+            .filter(|(_, cell)| !is_background(cell))
+            .map(|(point, _)| point);
+        let Some((from, to)) = content.fold(None, |bounds: Option<(Point, Point)>, point| {
+            Some(match bounds {
+                None => (point, point),
+                Some((from, to)) => (
+                    (from.0.min(point.0), from.1.min(point.1)),
+                    (to.0.max(point.0), to.1.max(point.1)),
+                ),
+            })
+        }) else {
+            return Grid::empty();
+        };
+        self.subgrid(from, to)
+    }
+}
+
+/// The transforms return a new parser with its cursor at the start, while `paste` keeps the cursor
+/// where it was. Only the transforms that turn lines into columns pad shorter lines with spaces.
+impl MultiLineParser {
+    fn transformed(&self, f: impl Fn(&Grid<char>) -> Grid<char>) -> MultiLineParser {
+        MultiLineParser::from(&f(&Grid::from(self)))
+    }
+
+    fn from_lines(lines: impl Iterator<Item = String>) -> MultiLineParser {
+        MultiLineParser::from_parsers(lines.map(|line| Parser::new_untrimmed(&line)).collect())
+    }
+
+    pub fn rotate_cw(&self) -> MultiLineParser {
+        self.transformed(Grid::rotate_cw)
+    }
+
+    pub fn rotate_ccw(&self) -> MultiLineParser {
+        self.transformed(Grid::rotate_ccw)
+    }
+
+    pub fn flip_h(&self) -> MultiLineParser {
+        self.transformed(Grid::flip_h)
+    }
+
+    /// Reverses the order of the lines, keeping their lengths
+    pub fn flip_v(&self) -> MultiLineParser {
+        MultiLineParser::from_lines(self.lines().iter().rev().map(Parser::to_string))
+    }
+
+    pub fn symmetries(&self) -> [MultiLineParser; 8] {
+        Grid::from(self)
+            .symmetries()
+            .map(|grid| MultiLineParser::from(&grid))
+    }
+
+    /// The characters between the corners `from` and `to`, both included, cutting each line
+    /// without padding it
+    pub fn subgrid(&self, from: Point, to: Point) -> MultiLineParser {
+        let (top, bottom) = (from.0.min(to.0), from.0.max(to.0));
+        let (left, right) = (from.1.min(to.1), from.1.max(to.1));
+        let lines = self.lines().iter().take(bottom + 1).skip(top);
+        MultiLineParser::from_lines(lines.map(|line| {
+            let chars = line.as_chars();
+            chars[left.min(chars.len())..(right + 1).min(chars.len())]
+                .iter()
+                .collect()
+        }))
+    }
+
+    /// Writes `other` over the characters from `at` on, dropping what falls past the end of a line
+    pub fn paste(&mut self, at: Point, other: &MultiLineParser) {
+        for (i, line) in other.lines().iter().enumerate() {
+            for (column, &c) in line.as_chars().iter().enumerate() {
+                self.set_at((at.0 + i, at.1 + column), c);
+            }
+        }
+    }
+
+    pub fn crop_to_content(&self, background: char) -> MultiLineParser {
+        let content = self.lines().iter().enumerate().flat_map(|(line, parser)| {
+            parser
+                .as_chars()
+                .iter()
+                .enumerate()
+                .filter(|&(_, &c)| c != background)
+                .map(move |(column, _)| (line, column))
+        });
+        let bounds = content.fold(None, |bounds: Option<(Point, Point)>, point| {
+            Some(match bounds {
+                None => (point, point),
+                Some((from, to)) => (
+                    (from.0.min(point.0), from.1.min(point.1)),
+                    (to.0.max(point.0), to.1.max(point.1)),
+                ),
+            })
+        });
+        match bounds {
+            Some((from, to)) => self.subgrid(from, to),
+            None => MultiLineParser::from_parsers(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::from(&MultiLineParser::new(text))
+    }
+
+    #[test]
+    fn test_rotate_non_square() {
+        let abc = grid("abc\ndef");
+        assert_eq!(abc.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(abc.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(abc.rotate_cw().rotate_ccw(), abc);
+        assert_eq!(abc.rotate_cw().rotate_cw(), abc.flip_h().flip_v());
+    }
+
+    #[test]
+    fn test_flip_and_transpose() {
+        let abc = grid("abc\ndef");
+        assert_eq!(abc.flip_h().to_string(), "cba\nfed\n");
+        assert_eq!(abc.flip_v().to_string(), "def\nabc\n");
+        assert_eq!(abc.transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let shape = grid("##.\n#..");
+        let mut symmetries = shape.symmetries().map(|g| g.to_string()).to_vec();
+        symmetries.sort();
+        symmetries.dedup();
+        assert_eq!(symmetries.len(), 8);
+        assert_eq!(
+            grid("#.\n.#")
+                .symmetries()
+                .iter()
+                .filter(|g| **g == grid("#.\n.#"))
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_subgrid_paste_and_crop() {
+        let mut area = grid("....\n....\n....");
+        area.paste((1, 2), &grid("##\n#."));
+        assert_eq!(area.to_string(), "....\n..##\n..#.\n");
+        assert_eq!(area.subgrid((2, 3), (1, 1)).to_string(), ".##\n.#.\n");
+        assert_eq!(area.subgrid((2, 2), (5, 9)).to_string(), "#.\n");
+        assert_eq!(area.crop_to_content(|&c| c == '.').to_string(), "##\n#.\n");
+        assert_eq!(grid("..").crop_to_content(|&c| c == '.').height(), 0);
+        assert_eq!(Grid::<char>::empty().subgrid((0, 0), (2, 2)), Grid::empty());
+        assert_eq!(grid("ab").subgrid((1, 0), (3, 1)).height(), 0);
+    }
+
+    #[test]
+    fn test_multi_line_parser() {
+        let mut parser = MultiLineParser::new("abc\ndef");
+        assert_eq!(parser.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(parser.symmetries()[4].to_string(), "cba\nfed\n");
+        parser.go_to((1, 2));
+        parser.paste((1, 1), &MultiLineParser::new("xyz"));
+        assert_eq!(parser.to_string(), "abc\ndxy\n");
+        assert_eq!(parser.point(), (1, 2));
+        assert_eq!(parser.subgrid((0, 1), (1, 2)).to_string(), "bc\nxy\n");
+        let parser = MultiLineParser::new("...\n.#.");
+        assert_eq!(parser.crop_to_content('.').to_string(), "#\n");
+    }
+
+    #[test]
+    fn test_ragged_multi_line_parser() {
+        let mut parser = MultiLineParser::new("abc\nd\nef");
+        parser.paste((0, 1), &MultiLineParser::new("xy\nzw"));
+        assert_eq!(parser.to_string(), "axy\nd\nef\n");
+        assert_eq!(parser.flip_v().to_string(), "ef\nd\naxy\n");
+        assert_eq!(parser.subgrid((0, 1), (2, 2)).to_string(), "xy\n\nf\n");
+        assert_eq!(
+            MultiLineParser::new("..\n.#..\n.")
+                .crop_to_content('.')
+                .to_string(),
+            "#\n"
+        );
+        assert_eq!(parser.rotate_cw().to_string(), "eda\nf x\n  y\n");
+    }
+}
@@ -0,0 +1,191 @@
+use crate::map::to_i64;
+use crate::{Direction, Grid, Point, PointI64};
+
+/// Maps a point anywhere on the plane onto a grid of `size` by wrapping both coordinates
+///
+/// # Panics
+///
+/// If either side of `size` is 0, since nothing can be wrapped onto an empty grid.
+pub fn wrap(point: PointI64, size: Point) -> Point {
+    assert!(size.0 > 0 && size.1 > 0, "cannot wrap onto an empty grid");
+    (
+        point.0.rem_euclid(size.0 as i64) as usize,
+        point.1.rem_euclid(size.1 as i64) as usize,
+    )
+}
+
+fn offset(point: PointI64, direction: &Direction) -> PointI64 {
+    let (line, column) = direction.offset();
+    (point.0 + line, point.1 + column)
+}
+
+/// A grid whose edges wrap around, so leaving one side re-enters on the opposite side.
+///
+/// Points are always reported on the base grid.
+pub struct Torus<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Torus<'a, T> {
+    /// # Panics
+    ///
+    /// If `grid` has no cells.
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(
+            grid.height() > 0 && grid.width() > 0,
+            "cannot wrap around an empty grid"
+        );
+        Self { grid }
+    }
+
+    fn size(&self) -> Point {
+        (self.grid.height(), self.grid.width())
+    }
+
+    pub fn wrap(&self, point: PointI64) -> Point {
+        wrap(point, self.size())
+    }
+
+    pub fn get(&self, point: PointI64) -> &'a T {
+        &self.grid[self.wrap(point)]
+    }
+
+    pub fn step(&self, point: Point, direction: &Direction) -> Point {
+        self.wrap(offset(to_i64(&point), direction))
+    }
+
+    /// The point reached after moving by `velocity` `steps` times
+    pub fn with_velocity(&self, point: Point, velocity: PointI64, steps: i64) -> Point {
+        let point = to_i64(&point);
+        self.wrap((point.0 + velocity.0 * steps, point.1 + velocity.1 * steps))
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_4)
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_8)
+    }
+
+    fn neighbors(
+        &self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        directions.iter().map(move |direction| {
+            let next = self.step(point, direction);
+            (next, &self.grid[next])
+        })
+    }
+}
+
+/// A grid repeated infinitely in every direction.
+///
+/// Points keep their position on the infinite plane and are only mapped back to the base grid for
+/// lookups.
+pub struct Tiled<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Tiled<'a, T> {
+    /// # Panics
+    ///
+    /// If `grid` has no cells.
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(
+            grid.height() > 0 && grid.width() > 0,
+            "cannot tile an empty grid"
+        );
+        Self { grid }
+    }
+
+    /// The point of the base grid that `point` is a copy of
+    pub fn base(&self, point: PointI64) -> Point {
+        wrap(point, (self.grid.height(), self.grid.width()))
+    }
+
+    /// Which copy of the grid `point` is in, `(0, 0)` being the base grid
+    pub fn tile(&self, point: PointI64) -> PointI64 {
+        (
+            point.0.div_euclid(self.grid.height() as i64),
+            point.1.div_euclid(self.grid.width() as i64),
+        )
+    }
+
+    pub fn get(&self, point: PointI64) -> &'a T {
+        &self.grid[self.base(point)]
+    }
+
+    pub fn neighbors4(&self, point: PointI64) -> impl Iterator<Item = (PointI64, &'a T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_4)
+    }
+
+    pub fn neighbors8(&self, point: PointI64) -> impl Iterator<Item = (PointI64, &'a T)> + '_ {
+        self.neighbors(point, &Direction::VALUES_8)
+    }
+
+    fn neighbors(
+        &self,
+        point: PointI64,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (PointI64, &'a T)> + '_ {
+        directions.iter().map(move |direction| {
+            let next = offset(point, direction);
+            (next, self.get(next))
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn torus(&self) -> Torus<'_, T> {
+        Torus::new(self)
+    }
+
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiLineParser;
+
+    #[test]
+    fn test_torus() {
+        let grid = Grid::from(&MultiLineParser::new("abc\ndef"));
+        let torus = grid.torus();
+        assert_eq!(torus.get((-1, -1)), &'f');
+        assert_eq!(torus.step((0, 0), &Direction::LeftUp), (1, 2));
+        assert_eq!(
+            torus.neighbors4((0, 2)).collect::<Vec<_>>(),
+            vec![
+                ((0, 0), &'a'),
+                ((1, 2), &'f'),
+                ((0, 1), &'b'),
+                ((1, 2), &'f')
+            ]
+        );
+        assert_eq!(torus.with_velocity((1, 0), (3, -4), 2), (1, 1));
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = Grid::from(&MultiLineParser::new("ab\ncd"));
+        let tiled = grid.tiled();
+        assert_eq!(tiled.get((5, -3)), &'d');
+        assert_eq!(tiled.base((5, -3)), (1, 1));
+        assert_eq!(tiled.tile((5, -3)), (2, -2));
+        assert_eq!(tiled.tile((1, 0)), (0, 0));
+        assert_eq!(
+            tiled.neighbors4((0, 0)).map(|(p, _)| p).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0), (0, -1), (-1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap((-11, 23), (10, 7)), (9, 2));
+    }
+}
@@ -0,0 +1,142 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Answers recorded for a single day, kept next to the cached input in `.data/yYYYY/dD.answers`.
+///
+/// Each line has the form `<part> <verdict> <answer>`, e.g. `1 correct 563` or `2 rejected 0`.
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Rejected,
+}
+
+impl Verdict {
+    /// Reads the verdict from the text returned by the Advent of Code answer page
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("That's not the right answer") {
+            Some(Verdict::Rejected)
+        } else {
+            None
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Rejected => "rejected",
+        }
+    }
+}
+
+impl AnswerStore {
+    pub fn load(year: u32, day: u8) -> Self {
+        let path = data_dir().join(format!("y{}/d{}.answers", year, day));
+        let answers = fs::read_to_string(&path)
+            .map(|content| content.lines().filter_map(parse_line).collect())
+            .unwrap_or_default();
+        Self { path, answers }
+    }
+
+    pub fn correct(&self, part: u8) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.part == part && a.verdict == Verdict::Correct)
+            .map(|a| a.value.as_str())
+    }
+
+    pub fn rejected(&self, part: u8) -> Vec<&str> {
+        self.answers
+            .iter()
+            .filter(|a| a.part == part && a.verdict == Verdict::Rejected)
+            .map(|a| a.value.as_str())
+            .collect()
+    }
+
+    pub fn record(&mut self, part: u8, verdict: Verdict, value: &str) -> std::io::Result<()> {
+        let answer = Answer {
+            part,
+            verdict,
+            value: value.to_string(),
+        };
+        if self.answers.contains(&answer) {
+            return Ok(());
+        }
+        fs::create_dir_all(self.path.parent().unwrap())?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{} {} {}", part, verdict.as_str(), value)?;
+        self.answers.push(answer);
+        Ok(())
+    }
+}
+
+pub fn data_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".data")
+}
+
+fn parse_line(line: &str) -> Option<Answer> {
+    let mut parts = line.splitn(3, ' ');
+    let part = parts.next()?.parse().ok()?;
+    let verdict = match parts.next()? {
+        "correct" => Verdict::Correct,
+        "rejected" => Verdict::Rejected,
+        _ => return None,
+    };
+    let value = parts.next()?.to_string();
+    Some(Answer {
+        part,
+        verdict,
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("2 rejected 1,2,3"),
+            Some(Answer {
+                part: 2,
+                verdict: Verdict::Rejected,
+                value: "1,2,3".to_string()
+            })
+        );
+        assert_eq!(parse_line("1 unknown 3"), None);
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::Rejected)
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently"),
+            None
+        );
+    }
+}
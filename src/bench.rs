@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Instant;
+
+use aoc_client::SolutionPart;
+
+use crate::answers::data_dir;
+use crate::auto_import;
+use crate::memory;
+use crate::registry;
+
+/// Slowdowns smaller than this are treated as noise regardless of the threshold
+const MIN_DELTA_MS: f64 = 1.0;
+
+/// Every part runs this many times and the median is recorded
+const RUNS: usize = 5;
+
+type Key = (u32, u8, u8);
+
+/// Timings of every registered solution for a single git commit, stored in `.data/bench/<commit>.txt`,
+/// with a `-dirty` suffix when the working tree had uncommitted changes.
+///
+/// Each line has the form `<year> <day> <part> <millis>`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub commit: String,
+    pub timings: BTreeMap<Key, f64>,
+}
+
+impl Snapshot {
+    /// Runs every registered solution with a cached input and records its median time, leaving
+    /// out the parts the registry marks as slow or unsolved
+    pub fn take() -> Self {
+        let mut snapshot = Snapshot {
+            commit: snapshot_name(),
+            ..Default::default()
+        };
+        for SolutionPart { year, day, part } in registry::solutions() {
+            let metadata = registry::day(year, day);
+            if metadata.is_some_and(|m| m.is_unsolved(part)) {
+                println!("y{}/d{} part {}: unsolved, skipping", year, day, part);
+                continue;
+            }
+            if metadata.is_some_and(|m| m.is_slow(part)) {
+                println!("y{}/d{} part {}: slow, skipping", year, day, part);
+                continue;
+            }
+            let Some(input) = registry::cached_input(year, day) else {
+                println!(
+                    "y{}/d{} part {}: no cached input, skipping",
+                    year, day, part
+                );
+                continue;
+            };
+            let func = auto_import::select_function(year, day as u32, part as u32).unwrap();
+            let mut timings = vec![];
+            for _ in 0..RUNS {
+                let input = input.clone();
+                memory::reset();
+                let now = Instant::now();
+                if panic::catch_unwind(|| func(input)).is_err() {
+                    break;
+                }
+                timings.push(now.elapsed().as_secs_f64() * 1000.0);
+            }
+            if timings.len() < RUNS {
+                println!("y{}/d{} part {}: panicked, skipping", year, day, part);
+                continue;
+            }
+            let elapsed = median(timings);
+            match memory::stats() {
+                Some(stats) => println!(
+                    "y{}/d{} part {}: {:.3}ms, {}",
+                    year, day, part, elapsed, stats
+                ),
+                None => println!("y{}/d{} part {}: {:.3}ms", year, day, part, elapsed),
+            }
+            snapshot.timings.insert((year, day, part), elapsed);
+        }
+        snapshot
+    }
+
+    /// Loads the snapshot named `commit` or the only one starting with it
+    pub fn load(commit: &str) -> Result<Self, String> {
+        let stems = fs::read_dir(snapshot_dir())
+            .map_err(|e| format!("Could not read the snapshots: {}", e))?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| Some(entry.path().file_stem()?.to_string_lossy().to_string()))
+            .collect::<Vec<_>>();
+        let stem = find_snapshot(commit, stems)?;
+        let content = fs::read_to_string(snapshot_dir().join(format!("{}.txt", stem)))
+            .map_err(|e| format!("Could not read snapshot {}: {}", stem, e))?;
+        Ok(Self::parse(stem, &content))
+    }
+
+    /// Loads the two most recently written snapshots, oldest first
+    pub fn latest_two() -> Option<(Self, Self)> {
+        let mut paths = fs::read_dir(snapshot_dir())
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+            .collect::<Vec<_>>();
+        paths.sort();
+        let stem = |i: usize| -> Option<String> {
+            Some(paths.get(i)?.1.file_stem()?.to_string_lossy().to_string())
+        };
+        let len = paths.len();
+        if len < 2 {
+            return None;
+        }
+        Some((
+            Self::load(&stem(len - 2)?).ok()?,
+            Self::load(&stem(len - 1)?).ok()?,
+        ))
+    }
+
+    pub fn save(&self) -> std::io::Result<PathBuf> {
+        let dir = snapshot_dir();
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.txt", self.commit));
+        fs::write(&path, self.to_string())?;
+        Ok(path)
+    }
+
+    fn parse(commit: String, content: &str) -> Self {
+        let timings = content
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let key = (
+                    parts.next()?.parse().ok()?,
+                    parts.next()?.parse().ok()?,
+                    parts.next()?.parse().ok()?,
+                );
+                Some((key, parts.next()?.parse().ok()?))
+            })
+            .collect();
+        Self { commit, timings }
+    }
+
+    /// Lists the solutions that got slower than `threshold` percent between `self` and `newer`
+    pub fn regressions(&self, newer: &Snapshot, threshold: f64) -> Vec<(Key, f64, f64)> {
+        self.timings
+            .iter()
+            .filter_map(|(key, &old)| {
+                let new = *newer.timings.get(key)?;
+                let slower = new - old > MIN_DELTA_MS && new > old * (1.0 + threshold / 100.0);
+                slower.then_some((*key, old, new))
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, part), millis) in &self.timings {
+            writeln!(f, "{} {} {} {:.3}", year, day, part, millis)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints every solution that slowed down between two snapshots
+pub fn report(old: &Snapshot, new: &Snapshot, threshold: f64) {
+    println!(
+        "Comparing {} -> {} (threshold {}%)",
+        old.commit, new.commit, threshold
+    );
+    let regressions = old.regressions(new, threshold);
+    if regressions.is_empty() {
+        println!("No regressions found");
+    }
+    for ((year, day, part), old, new) in regressions {
+        println!(
+            "SLOWER y{}/d{} part {}: {:.3}ms -> {:.3}ms (+{:.0}%)",
+            year,
+            day,
+            part,
+            old,
+            new,
+            (new / old - 1.0) * 100.0
+        );
+    }
+}
+
+fn snapshot_dir() -> PathBuf {
+    data_dir().join("bench")
+}
+
+fn median(mut timings: Vec<f64>) -> f64 {
+    timings.sort_by(f64::total_cmp);
+    timings[timings.len() / 2]
+}
+
+/// Picks the snapshot named exactly `commit`, or else the only one starting with it
+fn find_snapshot(commit: &str, mut stems: Vec<String>) -> Result<String, String> {
+    stems.retain(|stem| stem.starts_with(commit));
+    stems.sort();
+    if let Some(exact) = stems.iter().find(|stem| *stem == commit) {
+        return Ok(exact.clone());
+    }
+    match stems.as_slice() {
+        [] => Err(format!("No snapshot found for {}", commit)),
+        [stem] => Ok(stem.clone()),
+        _ => Err(format!(
+            "{} matches several snapshots: {}",
+            commit,
+            stems.join(", ")
+        )),
+    }
+}
+
+/// The checked out commit, marked dirty when tracked files have uncommitted changes
+fn snapshot_name() -> String {
+    let commit = git_commit().unwrap_or_else(|| "unknown".to_string());
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+    if dirty {
+        format!("{}-dirty", commit)
+    } else {
+        commit
+    }
+}
+
+/// Reads the hash of the checked out commit straight from `.git`
+fn git_commit() -> Option<String> {
+    let git = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".git");
+    let head = fs::read_to_string(git.join("HEAD")).ok()?;
+    let head = head.trim();
+    let Some(reference) = head.strip_prefix("ref: ") else {
+        return Some(head.to_string());
+    };
+    if let Ok(hash) = fs::read_to_string(git.join(reference)) {
+        return Some(hash.trim().to_string());
+    }
+    fs::read_to_string(git.join("packed-refs"))
+        .ok()?
+        .lines()
+        .find_map(|line| {
+            let (hash, name) = line.split_once(' ')?;
+            (name == reference).then(|| hash.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_snapshot() {
+        let snapshot = Snapshot::parse("abc".to_string(), "2024 16 1 12.500\n2024 16 2 3.000\n");
+        assert_eq!(snapshot.timings.get(&(2024, 16, 1)), Some(&12.5));
+        assert_eq!(
+            Snapshot::parse("abc".to_string(), &snapshot.to_string()),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn test_regressions() {
+        let old = Snapshot::parse("a".to_string(), "2024 1 1 10\n2024 1 2 10\n2024 2 1 0.1\n");
+        let new = Snapshot::parse(
+            "b".to_string(),
+            "2024 1 1 10.5\n2024 1 2 20\n2024 2 1 0.5\n",
+        );
+        assert_eq!(
+            old.regressions(&new, 10.0),
+            vec![((2024, 1, 2), 10.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_find_snapshot() {
+        let stems = vec![
+            "abc1".to_string(),
+            "abc1-dirty".to_string(),
+            "abd2".to_string(),
+        ];
+        assert_eq!(find_snapshot("abc1", stems.clone()), Ok("abc1".to_string()));
+        assert_eq!(find_snapshot("abd", stems.clone()), Ok("abd2".to_string()));
+        assert_eq!(
+            find_snapshot("ab", stems.clone()),
+            Err("ab matches several snapshots: abc1, abc1-dirty, abd2".to_string())
+        );
+        assert!(find_snapshot("fff", stems).is_err());
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(vec![3.0, 100.0, 1.0, 2.0, 2.5]), 2.5);
+    }
+}
@@ -0,0 +1,119 @@
+//! Tests every registered solution against the examples its day declares and against its real
+//! input, using the answers recorded in the answer store.
+use std::panic;
+
+use aoc_client::SolutionPart;
+
+use crate::answers::AnswerStore;
+use crate::auto_import;
+use crate::registry::{self, Day};
+
+fn run(solution_part: &SolutionPart, input: String) -> Option<String> {
+    let SolutionPart { year, day, part } = *solution_part;
+    let func = auto_import::select_function(year, day as u32, part as u32).ok()?;
+    panic::catch_unwind(|| func(input)).ok()
+}
+
+fn check(name: String, expected: &str, result: Option<String>, failures: &mut Vec<String>) {
+    match result {
+        Some(result) if result == expected => println!("{}: ok", name),
+        Some(result) => failures.push(format!("{}: expected {}, got {}", name, expected, result)),
+        None => failures.push(format!("{}: panicked", name)),
+    }
+}
+
+/// Resolves the `yYYYY` and `dD` module names the registry generates tests for
+fn registered(year: &str, day: &str) -> (u32, u8, &'static Day) {
+    let year = year[1..].parse().unwrap();
+    let day = day[1..].parse().unwrap();
+    let metadata = registry::day(year, day)
+        .unwrap_or_else(|| panic!("y{}/d{} is not in the registry", year, day));
+    (year, day, metadata)
+}
+
+fn solved_parts(metadata: &Day) -> impl Iterator<Item = u8> + '_ {
+    (1..=2).filter(|&part| !metadata.is_unsolved(part))
+}
+
+pub fn check_examples(year: &str, day: &str) {
+    let (year, day, metadata) = registered(year, day);
+    assert!(
+        !metadata.examples.is_empty(),
+        "y{}/d{} has no examples",
+        year,
+        day
+    );
+    let mut failures = vec![];
+    for part in solved_parts(metadata) {
+        let solution_part = SolutionPart::create(year, day, part);
+        for (i, example) in metadata.examples.iter().enumerate() {
+            let Some(expected) = example.answer(part) else {
+                continue;
+            };
+            let name = format!("y{}/d{} part {} example {}", year, day, part, i + 1);
+            let input = example.input.to_string();
+            let result = match example.solver_for(part) {
+                Some(solver) => panic::catch_unwind(|| solver(input)).ok(),
+                None => run(&solution_part, input),
+            };
+            check(name, expected, result, &mut failures);
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+pub fn check_inputs(year: &str, day: &str) {
+    let (year, day, metadata) = registered(year, day);
+    let mut failures = vec![];
+    for part in solved_parts(metadata).filter(|&part| !metadata.is_slow(part)) {
+        check_input(year, day, part, &mut failures);
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+fn check_input(year: u32, day: u8, part: u8, failures: &mut Vec<String>) {
+    let name = format!("y{}/d{} part {}", year, day, part);
+    let Some(input) = registry::cached_input(year, day) else {
+        println!("{}: skipped, no cached input", name);
+        return;
+    };
+    let expected = AnswerStore::load(year, day)
+        .correct(part)
+        .map(String::from)
+        .or_else(|| {
+            registry::day(year, day)?
+                .input_answer(part)
+                .map(String::from)
+        });
+    let Some(expected) = expected else {
+        println!("{}: skipped, no known answer", name);
+        return;
+    };
+    let solution_part = SolutionPart::create(year, day, part);
+    check(name, &expected, run(&solution_part, input), failures);
+}
+
+#[test]
+fn test_every_solution_is_registered() {
+    let missing = registry::solutions()
+        .into_iter()
+        .filter(|s| registry::day(s.year, s.day).is_none())
+        .map(|s| format!("y{}/d{}", s.year, s.day))
+        .collect::<Vec<_>>();
+    assert!(missing.is_empty(), "not in the registry: {:?}", missing);
+}
+
+#[test]
+#[ignore]
+fn test_registered_slow_inputs() {
+    let mut failures = vec![];
+    for SolutionPart { year, day, part } in registry::solutions() {
+        let Some(metadata) = registry::day(year, day) else {
+            continue;
+        };
+        if metadata.is_slow(part) && !metadata.is_unsolved(part) {
+            check_input(year, day, part, &mut failures);
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
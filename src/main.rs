@@ -1,11 +1,36 @@
+mod answers;
 mod auto_import;
+mod bench;
+#[cfg(test)]
+mod harness;
+mod memory;
+mod registry;
+mod sanity;
+mod watch;
 use std::time::Instant;
 use time::Month;
 
+use answers::{AnswerStore, Verdict};
 use aoc_client::{Client, SolutionPart};
 
 fn main() {
-    let solution_day = get_solution_day();
+    let (flags, args): (Vec<String>, Vec<String>) =
+        std::env::args().partition(|arg| arg.starts_with("--"));
+    if flags.iter().any(|f| f == "--bench") {
+        let snapshot = bench::Snapshot::take();
+        let path = snapshot.save().unwrap();
+        println!("Snapshot saved to {}", path.display());
+        return;
+    }
+    if flags.iter().any(|f| f == "--compare") {
+        compare(&flags, &args);
+        return;
+    }
+    let solution_day = get_solution_day(&args);
+    if flags.iter().any(|f| f == "--watch") {
+        watch::watch(&solution_day);
+        return;
+    }
     let SolutionPart { year, day, part } = solution_day;
     let client = Client::new().unwrap();
     println!("Getting input for year {} day {}...", year, day);
@@ -18,25 +43,65 @@ fn main() {
     // run puzzle solution
     let func = auto_import::select_function(year, day as u32, part as u32).unwrap();
     println!("Running function...");
+    memory::reset();
     let now = Instant::now();
     let result = func(input);
     println!("Completed in: {}ms", now.elapsed().as_secs_f64() * 1000.0);
+    if let Some(stats) = memory::stats() {
+        println!("Memory: {}", stats);
+    }
     // print result
     println!("Result: {}", result);
+    if flags.iter().any(|f| f == "--no-submit") {
+        return;
+    }
     // submit result?
-    if !prompt_for_input(
+    if prompt_for_input(
         "Press enter to exit or type anything to submit answer",
         "".to_string(),
     )
     .is_empty()
     {
-        let res = client.submit_solution(&solution_day, &result).unwrap();
-        println!("{}", res);
+        return;
+    }
+    // sanity check result
+    let warnings = sanity::check(&solution_day, &result);
+    if !warnings.is_empty() {
+        warnings.iter().for_each(|w| println!("Warning: {}", w));
+        if prompt_for_input("Type 'yes' to submit anyway", "".to_string()) != "yes" {
+            return;
+        }
+    }
+    let res = client.submit_solution(&solution_day, &result).unwrap();
+    println!("{}", res);
+    if let Some(verdict) = Verdict::from_response(&res) {
+        AnswerStore::load(year, day)
+            .record(part, verdict, &result)
+            .unwrap();
+    }
+}
+
+fn compare(flags: &[String], args: &[String]) {
+    let threshold = flags
+        .iter()
+        .find_map(|f| f.strip_prefix("--threshold="))
+        .and_then(|t| t.parse().ok())
+        .unwrap_or(10.0);
+    let snapshots = match (args.get(1), args.get(2)) {
+        (Some(old), Some(new)) => {
+            bench::Snapshot::load(old).and_then(|old| Ok((old, bench::Snapshot::load(new)?)))
+        }
+        _ => bench::Snapshot::latest_two().ok_or_else(|| {
+            "Could not find the snapshots to compare, run with --bench first".to_string()
+        }),
+    };
+    match snapshots {
+        Ok((old, new)) => bench::report(&old, &new, threshold),
+        Err(error) => println!("{}", error),
     }
 }
 
-fn get_solution_day() -> SolutionPart {
-    let args = std::env::args().collect::<Vec<String>>();
+fn get_solution_day(args: &[String]) -> SolutionPart {
     let current_time = time::OffsetDateTime::now_utc();
     // get year
     let current_year = current_time.year() as u32;
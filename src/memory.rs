@@ -0,0 +1,208 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+/// Global allocator that counts allocations, only installed with the `profile_memory` feature
+#[cfg_attr(feature = "profile_memory", global_allocator)]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+pub struct CountingAllocator {
+    allocations: AtomicUsize,
+    allocated: AtomicUsize,
+    resident: AtomicUsize,
+    lowest: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub allocations: usize,
+    pub allocated: usize,
+    pub peak: usize,
+}
+
+impl CountingAllocator {
+    const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            allocated: AtomicUsize::new(0),
+            resident: AtomicUsize::new(0),
+            lowest: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn grow(&self, size: usize) {
+        self.allocations.fetch_add(1, Relaxed);
+        self.allocated.fetch_add(size, Relaxed);
+        let resident = self.resident.fetch_add(size, Relaxed) + size;
+        self.record_peak(resident);
+    }
+
+    fn shrink(&self, size: usize) {
+        let resident = self.resident.fetch_sub(size, Relaxed) - size;
+        self.lowest.fetch_min(resident, Relaxed);
+    }
+
+    /// The peak counts bytes above the lowest point since the reset, so freeing memory that was
+    /// resident before the reset does not hide what is allocated afterwards
+    fn record_peak(&self, resident: usize) {
+        let lowest = self.lowest.load(Relaxed);
+        self.peak
+            .fetch_max(resident.saturating_sub(lowest), Relaxed);
+    }
+
+    /// A reallocation only adds the bytes it grows by and is not a new allocation
+    fn resize(&self, old_size: usize, new_size: usize) {
+        if new_size > old_size {
+            let growth = new_size - old_size;
+            self.allocated.fetch_add(growth, Relaxed);
+            let resident = self.resident.fetch_add(growth, Relaxed) + growth;
+            self.record_peak(resident);
+        } else {
+            self.shrink(old_size - new_size);
+        }
+    }
+
+    fn reset(&self) {
+        let resident = self.resident.load(Relaxed);
+        self.allocations.store(0, Relaxed);
+        self.allocated.store(0, Relaxed);
+        self.lowest.store(resident, Relaxed);
+        self.peak.store(0, Relaxed);
+    }
+
+    fn stats(&self) -> MemoryStats {
+        MemoryStats {
+            allocations: self.allocations.load(Relaxed),
+            allocated: self.allocated.load(Relaxed),
+            peak: self.peak.load(Relaxed),
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.resize(layout.size(), new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts a new measurement, the peak only counting bytes allocated on top of those resident
+/// from now on
+pub fn reset() {
+    ALLOCATOR.reset();
+}
+
+/// Returns the stats since the last `reset`, or `None` when the counting allocator is not installed
+pub fn stats() -> Option<MemoryStats> {
+    if !cfg!(feature = "profile_memory") {
+        return None;
+    }
+    Some(ALLOCATOR.stats())
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            bytes(self.allocated),
+            bytes(self.peak)
+        )
+    }
+}
+
+fn bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}{}", bytes, UNITS[0])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(512), "512B");
+        assert_eq!(bytes(1536), "1.5KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0MiB");
+    }
+
+    #[test]
+    fn test_counting_allocator() {
+        let allocator = CountingAllocator::new();
+        allocator.grow(100);
+        allocator.grow(50);
+        allocator.shrink(100);
+        allocator.grow(20);
+        assert_eq!(allocator.allocations.load(Relaxed), 3);
+        assert_eq!(allocator.allocated.load(Relaxed), 170);
+        assert_eq!(allocator.peak.load(Relaxed), 150);
+    }
+
+    #[test]
+    fn test_peak_after_reset() {
+        let allocator = CountingAllocator::new();
+        allocator.grow(1000);
+        allocator.reset();
+        allocator.grow(100);
+        allocator.resize(100, 300);
+        allocator.resize(300, 200);
+        assert_eq!(
+            allocator.stats(),
+            MemoryStats {
+                allocations: 1,
+                allocated: 300,
+                peak: 300
+            }
+        );
+    }
+
+    #[test]
+    fn test_peak_after_freeing_memory_from_before_reset() {
+        let allocator = CountingAllocator::new();
+        allocator.grow(1000);
+        allocator.reset();
+        allocator.shrink(1000);
+        allocator.grow(500);
+        allocator.shrink(500);
+        allocator.grow(200);
+        assert_eq!(allocator.stats().peak, 500);
+    }
+}
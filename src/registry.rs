@@ -0,0 +1,181 @@
+use std::fs;
+
+use aoc_client::SolutionPart;
+
+use crate::answers::data_dir;
+use crate::auto_import;
+
+const FIRST_YEAR: u32 = 2015;
+
+/// Lists every solution part the build script registered in `auto_import`
+pub fn solutions() -> Vec<SolutionPart> {
+    let last_year = time::OffsetDateTime::now_utc().year() as u32;
+    let mut solutions = vec![];
+    for year in FIRST_YEAR..=last_year {
+        for day in 1..=25 {
+            for part in 1..=2 {
+                if auto_import::select_function(year, day as u32, part as u32).is_ok() {
+                    solutions.push(SolutionPart::create(year, day, part));
+                }
+            }
+        }
+    }
+    solutions
+}
+
+/// Reads the cached input of a day without hitting the network
+pub fn cached_input(year: u32, day: u8) -> Option<String> {
+    fs::read_to_string(data_dir().join(format!("y{}/d{}.txt", year, day))).ok()
+}
+
+type Solver = fn(String) -> String;
+
+/// An example input from the puzzle text with the answers it gives for each part
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub input: &'static str,
+    answers: [Option<&'static str>; 2],
+    solvers: [Option<Solver>; 2],
+}
+
+impl Example {
+    pub const fn new(input: &'static str) -> Self {
+        Self {
+            input,
+            answers: [None, None],
+            solvers: [None, None],
+        }
+    }
+
+    pub const fn part1(mut self, answer: &'static str) -> Self {
+        self.answers[0] = Some(answer);
+        self
+    }
+
+    pub const fn part2(mut self, answer: &'static str) -> Self {
+        self.answers[1] = Some(answer);
+        self
+    }
+
+    /// Solves a part of this example with `solver` instead of the registered solution, for
+    /// puzzles whose examples use smaller sizes than the real input
+    pub const fn solver(mut self, part: u8, solver: Solver) -> Self {
+        self.solvers[part as usize - 1] = Some(solver);
+        self
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&'static str> {
+        self.answers[part as usize - 1]
+    }
+
+    #[cfg(test)]
+    pub fn solver_for(&self, part: u8) -> Option<Solver> {
+        self.solvers[part as usize - 1]
+    }
+}
+
+/// What the test harness knows about a day, declared as `DAY` next to its solution
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub examples: &'static [Example],
+    answers: [Option<&'static str>; 2],
+    slow: [bool; 2],
+    unsolved: [bool; 2],
+}
+
+impl Day {
+    pub const fn new(examples: &'static [Example]) -> Self {
+        Self {
+            examples,
+            answers: [None, None],
+            slow: [false, false],
+            unsolved: [false, false],
+        }
+    }
+
+    /// The known answer for the real input, used when the answer store has none
+    pub const fn answer(mut self, part: u8, answer: &'static str) -> Self {
+        self.answers[part as usize - 1] = Some(answer);
+        self
+    }
+
+    /// Marks a part as too slow for a regular test run
+    pub const fn slow(mut self, part: u8) -> Self {
+        self.slow[part as usize - 1] = true;
+        self
+    }
+
+    /// Marks a part whose solution is not written yet, so its checks are skipped
+    pub const fn unsolved(mut self, part: u8) -> Self {
+        self.unsolved[part as usize - 1] = true;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn input_answer(&self, part: u8) -> Option<&'static str> {
+        self.answers[part as usize - 1]
+    }
+
+    pub fn is_slow(&self, part: u8) -> bool {
+        self.slow[part as usize - 1]
+    }
+
+    pub fn is_unsolved(&self, part: u8) -> bool {
+        self.unsolved[part as usize - 1]
+    }
+
+    pub fn example_answers(&self, part: u8) -> Vec<&'static str> {
+        self.examples
+            .iter()
+            .filter_map(|example| example.answer(part))
+            .collect()
+    }
+}
+
+/// Builds the lookup from a year and day to its `DAY`, along with one example test and one input
+/// test per day
+macro_rules! days {
+    ($($year:ident: [$($day:ident),* $(,)?]),* $(,)?) => {
+        /// The metadata of a registered day
+        pub fn day(year: u32, day: u8) -> Option<&'static Day> {
+            match format!("y{}::d{}", year, day).as_str() {
+                $($(
+                    concat!(stringify!($year), "::", stringify!($day)) => {
+                        Some(&auto_import::$year::$day::DAY)
+                    }
+                )*)*
+                _ => None,
+            }
+        }
+
+        #[cfg(test)]
+        mod examples {
+            $(mod $year {
+                $(#[test]
+                fn $day() {
+                    crate::harness::check_examples(stringify!($year), stringify!($day));
+                })*
+            })*
+        }
+
+        #[cfg(test)]
+        mod inputs {
+            $(mod $year {
+                $(#[test]
+                fn $day() {
+                    crate::harness::check_inputs(stringify!($year), stringify!($day));
+                })*
+            })*
+        }
+    };
+}
+
+days! {
+    y2023: [d1, d2, d3],
+    y2024: [
+        d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19, d20,
+        d21, d22, d23,
+    ],
+    y2025: [d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12],
+}
@@ -0,0 +1,78 @@
+use aoc_client::SolutionPart;
+
+use crate::answers::AnswerStore;
+use crate::registry;
+
+/// Answers with more digits than this overflowed or concatenated numbers by mistake
+const MAX_DIGITS: usize = 20;
+
+/// Lists the reasons an answer is probably wrong, empty if it looks fine to submit
+pub fn check(solution_part: &SolutionPart, answer: &str) -> Vec<String> {
+    let SolutionPart { year, day, part } = *solution_part;
+    let store = AnswerStore::load(year, day);
+    let examples = registry::day(year, day)
+        .map(|metadata| metadata.example_answers(part))
+        .unwrap_or_default();
+    check_answer(answer, &examples, &store.rejected(part))
+}
+
+fn check_answer(answer: &str, examples: &[&str], rejected: &[&str]) -> Vec<String> {
+    let mut warnings = vec![];
+    if answer.is_empty() {
+        warnings.push("answer is empty".to_string());
+    }
+    if answer.chars().any(char::is_whitespace) {
+        warnings.push("answer contains whitespace or newlines".to_string());
+    }
+    if examples.contains(&answer) {
+        warnings.push("answer is the same as the example answer".to_string());
+    }
+    if rejected.contains(&answer) {
+        warnings.push("answer was already rejected".to_string());
+    }
+    if let Ok(number) = answer.parse::<i128>() {
+        if number < 0 {
+            warnings.push("answer is negative".to_string());
+        } else if number == 0 {
+            warnings.push("answer is zero".to_string());
+        }
+    }
+    let digits = answer.trim_start_matches('-');
+    if digits.len() > MAX_DIGITS && digits.chars().all(|c| c.is_ascii_digit()) {
+        warnings.push(format!("answer has more than {} digits", MAX_DIGITS));
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plausible_answer() {
+        assert!(check_answer("12345", &["11"], &["100"]).is_empty());
+        assert!(check_answer("1,6,7,4", &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_suspicious_answers() {
+        assert_eq!(check_answer("", &[], &[]), vec!["answer is empty"]);
+        assert_eq!(check_answer("0", &[], &[]), vec!["answer is zero"]);
+        assert_eq!(check_answer("-3", &[], &[]), vec!["answer is negative"]);
+        assert_eq!(
+            check_answer("Some(3)\n", &[], &[]),
+            vec!["answer contains whitespace or newlines"]
+        );
+        assert_eq!(
+            check_answer("11", &["11"], &["11"]),
+            vec![
+                "answer is the same as the example answer",
+                "answer was already rejected"
+            ]
+        );
+        assert_eq!(
+            check_answer("123456789012345678901", &[], &[]),
+            vec!["answer has more than 20 digits"]
+        );
+    }
+}
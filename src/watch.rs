@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+use aoc_client::SolutionPart;
+
+use crate::answers::AnswerStore;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reruns the example tests and the real input of a day every time its source file or one of
+/// the shared `parser`/`algorithms` crates changes.
+pub fn watch(solution_day: &SolutionPart) {
+    let SolutionPart { year, day, part } = *solution_day;
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let watched = [
+        root.join(format!("src/y{}/d{}.rs", year, day)),
+        root.join("parser"),
+        root.join("algorithms"),
+    ];
+    let mut previous: Option<String> = None;
+    let mut last_change = None;
+
+    println!(
+        "Watching year {} day {} part {} (ctrl-c to stop)...",
+        year, day, part
+    );
+    loop {
+        let change = latest_change(&watched);
+        if change != last_change {
+            last_change = change;
+            println!(
+                "\n==================== y{}/d{} ====================",
+                year, day
+            );
+            run_example_tests(year, day);
+            if let Some(result) = run_input(year, day, part) {
+                report(&result, previous.as_deref(), year, day, part);
+                previous = Some(result);
+            }
+        }
+        sleep(POLL_INTERVAL);
+    }
+}
+
+fn latest_change(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths.iter().filter_map(|p| modified(p)).max()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return metadata.modified().ok();
+    }
+    fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() != "target")
+        .filter_map(|entry| modified(&entry.path()))
+        .max()
+}
+
+fn cargo(subcommand: &str) -> Command {
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .arg(subcommand)
+        .arg("--quiet")
+        .current_dir(env!("CARGO_MANIFEST_DIR"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+}
+
+fn run_example_tests(year: u32, day: u8) {
+    println!("Running example tests...");
+    let output = cargo("test")
+        .args(["--bin", "advent", "--"])
+        .arg(format!("registry::examples::y{}::d{}", year, day))
+        .arg("--exact")
+        .stderr(Stdio::inherit())
+        .output();
+    let Ok(output) = output else {
+        println!("Example tests failed!");
+        return;
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{}", stdout);
+    if !output.status.success() {
+        println!("Example tests failed!");
+    } else if tests_run(&stdout) == Some(0) {
+        println!("No example tests ran for y{}/d{}!", year, day);
+    }
+}
+
+/// The count from the `running N tests` line of the test harness output
+fn tests_run(stdout: &str) -> Option<usize> {
+    stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("running "))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|count| count.parse().ok())
+}
+
+/// Runs the freshly built binary on the real input and returns the printed result
+fn run_input(year: u32, day: u8, part: u8) -> Option<String> {
+    println!("Running real input...");
+    let output = cargo("run")
+        .args(["--", "--no-submit"])
+        .args([year.to_string(), day.to_string(), part.to_string()])
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{}", stdout);
+    if !output.status.success() {
+        println!("Run failed!");
+        return None;
+    }
+    let (_, result) = stdout.rsplit_once("Result: ")?;
+    Some(result.trim_end().to_string())
+}
+
+fn report(result: &str, previous: Option<&str>, year: u32, day: u8, part: u8) {
+    match previous {
+        Some(previous) if previous == result => println!("Previous run: unchanged"),
+        Some(previous) => println!("Previous run: changed (was {})", previous),
+        None => (),
+    }
+    match AnswerStore::load(year, day).correct(part) {
+        Some(correct) if correct == result => println!("Known answer: matches"),
+        Some(correct) => println!("Known answer: differs (expected {})", correct),
+        None => println!("Known answer: none recorded"),
+    }
+}
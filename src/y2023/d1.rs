@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 #[allow(unused_imports)]
 use std::{error::Error, fs};
 
@@ -120,17 +121,21 @@ pub fn part2(input: String) -> String {
     sum.to_string()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example() {
-        let str = "1abc2
+const INPUT: &str = "1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet";
-        let result = part1(str.to_string());
-        assert_eq!(result, "142");
-    }
-}
+
+const INPUT2: &str = "
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT).part1("142"),
+    Example::new(INPUT2).part2("281"),
+]);
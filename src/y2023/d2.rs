@@ -1,3 +1,5 @@
+use crate::registry::{Day, Example};
+
 #[derive(Debug)]
 pub struct Game {
     pub sets: Vec<Vec<(Qube, u32)>>,
@@ -134,3 +136,12 @@ pub fn part2(input: String) -> String {
     }
     sum_part2.to_string()
 }
+
+const INPUT: &str = "
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("8").part2("2286")]);
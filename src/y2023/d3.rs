@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone)]
@@ -217,3 +218,17 @@ pub fn part1(input: String) -> String {
 pub fn part2(input: String) -> String {
     part1(input)
 }
+
+const INPUT: &str = "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("4361").part2("467835")]).unsolved(2);
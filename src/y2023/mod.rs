@@ -1,17 +1,10 @@
 //! Auto-generated file by build script, do not edit!
-pub mod d1;
 pub mod d2;
 pub mod d3;
+pub mod d1;
 /// Selects the function for the given day and part
 pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, String> {
     match day {
-        1 => {
-            match part {
-                1 => Ok(d1::part1),
-                2 => Ok(d1::part2),
-                _ => Err("Invalid part!".into()),
-            }
-        }
         2 => {
             match part {
                 1 => Ok(d2::part1),
@@ -26,6 +19,13 @@ pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, Stri
                 _ => Err("Invalid part!".into()),
             }
         }
+        1 => {
+            match part {
+                1 => Ok(d1::part1),
+                2 => Ok(d1::part2),
+                _ => Err("Invalid part!".into()),
+            }
+        }
         _ => Err("Invalid day!".into()),
     }
 }
@@ -1,8 +1,11 @@
+use crate::registry::{Day, Example};
+use parser::MultiLineParser;
+
 pub fn part1(input: String) -> String {
     let (mut first_list, mut second_list) = get_two_lists_from_input(input);
     first_list.sort();
     second_list.sort();
-    let distance: i32 = first_list
+    let distance: i64 = first_list
         .iter()
         .zip(second_list.iter())
         .map(|(f, s)| (f - s).abs())
@@ -14,73 +17,26 @@ pub fn part2(input: String) -> String {
     let (first_list, second_list) = get_two_lists_from_input(input);
     let mut sum = 0;
     for i in first_list.iter() {
-        sum += i * second_list.iter().filter(|s| *s == i).count() as i32;
+        sum += i * second_list.iter().filter(|s| *s == i).count() as i64;
     }
     sum.to_string()
 }
 
-fn get_two_lists_from_input(input: String) -> (Vec<i32>, Vec<i32>) {
-    let lines: Vec<String> = input.split('\n').map(|s| s.to_string()).collect();
-    let lists: Vec<(i32, i32)> = lines
-        .iter()
-        .filter(|s| !s.is_empty())
-        .map(|s| {
-            let mut nums = s.split_whitespace().map(|s| s.parse::<i32>().unwrap());
-            let first_num = nums.next().unwrap();
-            let second_num = nums.next().unwrap();
-            (first_num, second_num)
-        })
-        .collect();
-    let first_list: Vec<i32> = lists.iter().map(|e| e.0).collect();
-    let second_list: Vec<i32> = lists.iter().map(|e| e.1).collect();
+fn get_two_lists_from_input(input: String) -> (Vec<i64>, Vec<i64>) {
+    let mut columns = MultiLineParser::new(&input).split_columns_whitespace();
+    let second_list = columns.pop().unwrap();
+    let first_list = columns.pop().unwrap();
     (first_list, second_list)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3
-";
-
-    #[test]
-    fn test_example() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "11");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let str = "3   4
+const INPUT: &str = "3   4
 4   3
 2   5
 1   3
 3   9
 3   3
 ";
-        let result = part2(str.to_string());
-        assert_eq!(result, "31");
-    }
 
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d1.txt").to_string()),
-            "2285373"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d1.txt").to_string()),
-            "21142653"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("11").part2("31")])
+    .answer(1, "2285373")
+    .answer(2, "21142653");
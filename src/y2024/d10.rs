@@ -1,73 +1,55 @@
-use parser::{Direction, Map, MultiLineParser, Point};
+use crate::registry::{Day, Example};
+use parser::{Grid, Map, MultiLineParser, Point};
 use std::collections::HashSet;
 
 pub fn part1(input: String) -> String {
-    let (mut parser, map) = parse_input(&input);
+    let (heights, map) = parse_input(&input);
     let starts = map.get(&'0').unwrap();
 
-    let sum = starts
-        .iter()
-        .map(|p| dfs(&mut parser, p, '9'))
-        .sum::<usize>();
+    let sum = starts.iter().map(|p| dfs(&heights, p, 9)).sum::<usize>();
     sum.to_string()
 }
 
 pub fn part2(input: String) -> String {
-    let (mut parser, map) = parse_input(&input);
+    let (heights, map) = parse_input(&input);
     let starts = map.get(&'0').unwrap();
 
     let sum = starts
         .iter()
-        .map(|p| dfs_part2(&mut parser, p, '9'))
+        .map(|p| dfs_part2(&heights, p, 9))
         .sum::<usize>();
     sum.to_string()
 }
 
-fn parse_input(input: &str) -> (MultiLineParser, Map) {
+fn parse_input(input: &str) -> (Grid<u32>, Map) {
     let mut parser = MultiLineParser::new(input);
     let map: Map = (&mut parser).into();
-    (parser, map)
+    let heights = Grid::from_parser(&parser, |c| c.to_digit(10).unwrap_or(0));
+    (heights, map)
 }
 
-fn dfs(parser: &mut MultiLineParser, source: &Point, needle: char) -> usize {
-    parser.go_to(*source);
-    let points = walk(parser, needle);
+fn dfs(heights: &Grid<u32>, source: &Point, needle: u32) -> usize {
+    let points = walk(heights, *source, needle);
     let set: HashSet<&Point> = points.iter().collect();
     set.len()
 }
 
-fn dfs_part2(parser: &mut MultiLineParser, source: &Point, needle: char) -> usize {
-    parser.go_to(*source);
-    let points = walk(parser, needle);
-    points.len()
+fn dfs_part2(heights: &Grid<u32>, source: &Point, needle: u32) -> usize {
+    walk(heights, *source, needle).len()
 }
 
-fn walk(parser: &mut MultiLineParser, needle: char) -> Vec<Point> {
-    if parser.peek() == Some(&needle) {
-        return vec![parser.point()];
+fn walk(heights: &Grid<u32>, point: Point, needle: u32) -> Vec<Point> {
+    if heights[point] == needle {
+        return vec![point];
     }
-    let mut points = vec![];
-    for direction in Direction::VALUES_4 {
-        let next = parser
-            .peek_next_with_direction(&direction)
-            .unwrap_or(&'0')
-            .to_string();
-        let cur = parser.peek().unwrap_or(&'0').to_string();
-
-        if next.parse::<usize>().unwrap() == cur.parse::<usize>().unwrap() + 1 {
-            parser.advance_with_direction(1, &direction);
-            points.append(&mut walk(parser, needle));
-            parser.advance_with_direction(1, &direction.opposite());
-        }
-    }
-
-    points
+    heights
+        .neighbors4(point)
+        .filter(|(_, &height)| height == heights[point] + 1)
+        .flat_map(|(next, _)| walk(heights, next, needle))
+        .collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 89010123
 78121874
 87430965
@@ -77,33 +59,6 @@ mod tests {
 01329801
 10456732";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "36");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "81");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d10.txt").to_string()),
-            "820"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d10.txt").to_string()),
-            "1786"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("36").part2("81")])
+    .answer(1, "820")
+    .answer(2, "1786");
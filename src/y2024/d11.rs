@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use cached::proc_macro::cached;
 
 use parser::Parser;
@@ -37,11 +38,16 @@ fn blink_n(stone_num: i64, num: usize) -> usize {
     map.sum::<usize>()
 }
 
+const INPUT: &str = "
+125 17";
+
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("55312").part2("65601038650482")])
+    .answer(1, "217443")
+    .answer(2, "257246536026785");
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "
-125 17";
 
     #[test]
     fn test_stone_blink() {
@@ -50,34 +56,4 @@ mod tests {
         assert_eq!(blink(0), vec![1]);
         assert_eq!(blink(1), vec![2024]);
     }
-
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "55312");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "65601038650482");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d11.txt").to_string()),
-            "217443"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d11.txt").to_string()),
-            "257246536026785"
-        );
-    }
 }
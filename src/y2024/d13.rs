@@ -1,4 +1,5 @@
-use parser::{Parser, Point, Solvable};
+use crate::registry::{Day, Example};
+use parser::{MultiLineParser, Point, Regex, Sections, Solvable};
 
 pub fn part1(input: String) -> String {
     let games = parse_input(&input);
@@ -39,46 +40,18 @@ pub fn part2(input: String) -> String {
 type Game = (Point, Point, Point);
 
 fn parse_input(input: &str) -> Vec<Game> {
-    let games = input
-        .split("\n\n")
-        .map(|game_str| {
-            let parts = game_str
-                .split("\n")
-                .filter(|l| !l.is_empty())
-                .collect::<Vec<&str>>();
-            let ab = parts
-                .iter()
-                .take(2)
-                .map(|line| {
-                    let mut parser = Parser::new(line);
-                    parser.advance_to("X+");
-                    parser.advance(1);
-                    let x = parser.match_number_up_to(',').unwrap();
-                    parser.advance_to("Y+");
-                    parser.advance(1);
-                    let y = parser.match_number().unwrap();
-                    (x as usize, y as usize)
-                })
-                .collect::<Vec<Point>>();
-            let mut parser = Parser::new(parts[2]);
-            parser.advance_to("X=");
-            parser.advance(1);
-            let x = parser.match_number_up_to(',').unwrap() as usize;
-            parser.advance_to("Y=");
-            parser.advance(1);
-            let y = parser.match_number().unwrap() as usize;
-
-            (ab[0], ab[1], (x, y))
+    let regex = Regex::new(r"X[+=](\d+), Y[+=](\d+)").unwrap();
+    Sections::new(input)
+        .all::<MultiLineParser>()
+        .iter_mut()
+        .map(|game| {
+            let points = game.map_lines_regex::<Point>(&regex).unwrap();
+            (points[0], points[1], points[2])
         })
-        .collect();
-
-    games
+        .collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
@@ -96,31 +69,6 @@ Button B: X+27, Y+71
 Prize: X=18641, Y=10279
 ";
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(INPUT.to_string()), "480");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT.to_string()), "875318608908");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d13.txt").to_string()),
-            "31065"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d13.txt").to_string()),
-            "93866170395343"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("480").part2("875318608908")])
+    .answer(1, "31065")
+    .answer(2, "93866170395343");
@@ -1,6 +1,7 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
-use parser::{Nearable, Point, PointI64};
+use parser::{MultiLineParser, Nearable, Point, PointI64};
 
 pub fn part1(input: String) -> String {
     let robots = parse_input(&input);
@@ -82,28 +83,14 @@ fn _print_points(points: &[&Point], max: &Point) {
 type Robot = (Point, PointI64);
 
 fn parse_input(input: &str) -> Vec<Robot> {
-    let robots = input
-        .split("\n")
-        .filter(|l| !l.is_empty())
-        .map(|game_str| {
-            let parts = game_str.split(' ').collect::<Vec<&str>>();
-            let p = parts[0].split_once('=').unwrap().1.split_once(',').unwrap();
-            let x = p.0.parse().unwrap();
-            let y = p.1.parse().unwrap();
-
-            let v = parts[1].split_once('=').unwrap().1.split_once(',').unwrap();
-            let vx = v.0.parse().unwrap();
-            let vy = v.1.parse().unwrap();
-            ((x, y), (vx, vy))
-        })
-        .collect();
-    robots
+    MultiLineParser::new(input)
+        .extract_all_numbers()
+        .iter()
+        .map(|n| ((n[0] as usize, n[1] as usize), (n[2], n[3])))
+        .collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
@@ -118,32 +105,6 @@ p=2,4 v=2,-3
 p=9,5 v=-3,-3
 ";
 
-    #[test]
-    fn test_part1() {
-        // 12 if max is 11,7
-        assert_eq!(part1(INPUT.to_string()), "21");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT.to_string()), "19");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d14.txt").to_string()),
-            "210587128"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d14.txt").to_string()),
-            "7286"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("21").part2("19")])
+    .answer(1, "210587128")
+    .answer(2, "7286");
@@ -1,6 +1,7 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
-use parser::{Direction, MultiLineParser, Parser};
+use parser::{Direction, MultiLineParser, Parser, Sections};
 
 pub fn part1(input: String) -> String {
     let (mut map, mut moves) = parse_input(&input);
@@ -63,16 +64,11 @@ pub fn part2(input: String) -> String {
 }
 
 fn parse_input(input: &str) -> (MultiLineParser, Parser) {
-    let (map_str, moves_str) = input.split_once("\n\n").unwrap();
-    let map = MultiLineParser::new(map_str);
-    let moves = Parser::new(moves_str);
-
-    (map, moves)
+    Sections::new(input).pair()
 }
 
 fn parse_input_2(input: &str) -> (MultiLineParser, Parser) {
-    let (map_str, moves_str) = input.split_once("\n\n").unwrap();
-    let moves = Parser::new(moves_str);
+    let (map_str, moves) = Sections::new(input).pair::<&str, Parser>();
     let mut str = String::new();
 
     for char in map_str.chars() {
@@ -174,10 +170,7 @@ fn move_robot_2(parser: &mut MultiLineParser, direction: &Direction) {
     // );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 ##########
 #..O..O.O#
 #......O.#
@@ -200,7 +193,7 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 
-    const INPUT2: &str = "########
+const INPUT2: &str = "########
 #..O.O.#
 ##@.O..#
 #...O..#
@@ -210,7 +203,8 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 ########
 
 <^^>>>vv<v>>v<<";
-    const INPUT3: &str = "
+
+const INPUT3: &str = "
 #######
 #...#.#
 #.....#
@@ -222,33 +216,10 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 <vv<<^^<<^^
 ";
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(INPUT2.to_string()), "2028");
-        assert_eq!(part1(INPUT.to_string()), "10092");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT.to_string()), "9021");
-        assert_eq!(part2(INPUT3.to_string()), "618");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d15.txt").to_string()),
-            "1412971"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d15.txt").to_string()),
-            "1429299"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT2).part1("2028"),
+    Example::new(INPUT).part1("10092").part2("9021"),
+    Example::new(INPUT3).part2("618"),
+])
+.answer(1, "1412971")
+.answer(2, "1429299");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::{HashMap, VecDeque};
 
 use parser::{Direction, MultiLineParser, Nearable, Point};
@@ -120,10 +121,7 @@ fn get_neighbors(map: &MultiLineParser, direction: &Direction) -> Vec<(Point, Di
     neighbors
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 ###############
 #.......#....E#
 #.#.###.#.###.#
@@ -141,7 +139,7 @@ mod tests {
 ###############
 ";
 
-    const INPUT2: &str = "
+const INPUT2: &str = "
 #################
 #...#...#...#..E#
 #.#.#.#.#.#.#.#.#
@@ -161,33 +159,9 @@ mod tests {
 #################
 ";
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(INPUT.to_string()), "7036");
-        assert_eq!(part1(INPUT2.to_string()), "11048");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT.to_string()), "45");
-        assert_eq!(part2(INPUT2.to_string()), "64");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d16.txt").to_string()),
-            "134588"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d16.txt").to_string()),
-            "631"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT).part1("7036").part2("45"),
+    Example::new(INPUT2).part1("11048").part2("64"),
+])
+.answer(1, "134588")
+.answer(2, "631");
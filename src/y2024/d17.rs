@@ -1,4 +1,5 @@
-use parser::{MultiLineParser, Parser};
+use crate::registry::{Day, Example};
+use parser::{MultiLineParser, Parser, Sections};
 pub fn part1(input: String) -> String {
     let (registers, instructions) = parse_input(&input);
     let (a, b, c) = registers;
@@ -7,7 +8,7 @@ pub fn part1(input: String) -> String {
 }
 
 pub fn part2(input: String) -> String {
-    let (_, instructions_str) = input.split_once("\n\n").unwrap();
+    let (_, instructions_str) = Sections::new(&input).pair::<&str, &str>();
     let (_, instructions_str) = instructions_str.split_once(" ").unwrap();
     let instructions_str = instructions_str.replace(",", "");
     let instructions_str = instructions_str.trim();
@@ -51,17 +52,15 @@ pub fn part2(input: String) -> String {
 }
 
 fn parse_input(input: &str) -> ((i128, i128, i128), Vec<(i128, i128)>) {
-    let (registers_str, instructions_str) = input.split_once("\n\n").unwrap();
-    let mut registers = MultiLineParser::new(registers_str);
+    let (mut registers, mut instructions) = Sections::new(input).pair::<MultiLineParser, Parser>();
     registers.advance_all_lines(12);
     let registers = registers
-        .match_number()
+        .match_number::<i128>()
         .iter()
-        .map(|p| p.unwrap() as i128)
+        .map(|p| p.unwrap())
         .collect::<Vec<i128>>();
     let (a, b, c) = (registers[0], registers[1], registers[2]);
 
-    let mut instructions = Parser::new(instructions_str);
     instructions.advance_to(" ");
     instructions.advance(1);
     let instructions = instructions.split_to_numbers(",");
@@ -110,17 +109,14 @@ fn run(
     out
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 Register A: 729
 Register B: 0
 Register C: 0
 
 Program: 0,1,5,4,3,0";
 
-    const INPUT2: &str = "
+const INPUT2: &str = "
 Register A: 2024
 Register B: 0
 Register C: 0
@@ -128,33 +124,9 @@ Register C: 0
 Program: 0,3,5,4,3,0
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "4,6,3,5,6,3,5,2,1,0");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT2.to_string()), "117440");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d17.txt").to_string()),
-            "1,6,7,4,3,0,5,0,6"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_slow")]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d17.txt").to_string()),
-            "107862689"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT).part1("4,6,3,5,6,3,5,2,1,0"),
+    Example::new(INPUT2).part2("117440"),
+])
+.answer(1, "1,6,7,4,3,0,5,0,6")
+.slow(2);
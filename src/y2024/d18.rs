@@ -1,25 +1,30 @@
+use crate::registry::{Day, Example};
 use parser::MultiLineParser;
 pub fn part1(input: String) -> String {
     let input = parse_input(input);
     solve_for_max_size(input, 70, 1024).unwrap().to_string()
 }
 
-fn _example_part1(input: String) -> String {
+fn example_part1(input: String) -> String {
     let input = parse_input(input);
     solve_for_max_size(input, 6, 12).unwrap().to_string()
 }
 
 pub fn part2(input: String) -> String {
-    example_part_2(input, 70)
+    first_blocking_byte(input, 70)
 }
 
-fn example_part_2(input: String, space_size: usize) -> String {
+fn example_part2(input: String) -> String {
+    first_blocking_byte(input, 6)
+}
+
+fn first_blocking_byte(input: String, space_size: usize) -> String {
     let input = parse_input(input);
     let mut parser = MultiLineParser::create('.', (space_size + 1, space_size + 1));
     for i in 0.. {
         let (a, b) = input[i];
         parser.go_to((b, a)).set(&'#');
-        let solution = algorithms::dijkstra((0, 0), (space_size, space_size), &mut parser).0;
+        let solution = algorithms::dijkstra((0, 0), (space_size, space_size), &parser).0;
         if solution.is_none() {
             return format!("{},{}", a, b);
         }
@@ -41,14 +46,10 @@ fn solve_for_max_size(input: Vec<(usize, usize)>, space_size: usize, take: usize
         .iter()
         .take(take)
         .for_each(|&(a, b)| parser.go_to((b, a)).set(&'#'));
-    algorithms::dijkstra((0, 0), (space_size, space_size), &mut parser).0
+    algorithms::dijkstra((0, 0), (space_size, space_size), &parser).0
 }
 
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 5,4
 4,2
 4,5
@@ -76,33 +77,10 @@ mod tests {
 2,0
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = _example_part1(INPUT.to_string());
-        assert_eq!(result, "22");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = example_part_2(INPUT.to_string(), 6);
-        assert_eq!(result, "6,1");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d18.txt").to_string()),
-            "272"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_slow")]
-    fn test_part2_input() {
-        // assert_eq!(
-        //     part2(include_str!("../../.data/y2024/d18.txt").to_string()),
-        //     "16,44"
-        // );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT)
+    .part1("22")
+    .part2("6,1")
+    .solver(1, example_part1)
+    .solver(2, example_part2)])
+.answer(1, "272")
+.slow(2);
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::VecDeque;
 pub fn part1(input: String) -> String {
     let (towels, patterns) = parse_input(input);
@@ -64,10 +65,7 @@ fn bfs(pattern: &str, towels: &Vec<String>) -> usize {
     path_count[pattern.len()]
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 r, wr, b, g, bwu, rb, gb, br
 
 brwrr
@@ -80,33 +78,6 @@ brgr
 bbrgwb
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "6");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "16");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d19.txt").to_string()),
-            "308"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d19.txt").to_string()),
-            "662726441391898"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("6").part2("16")])
+    .answer(1, "308")
+    .answer(2, "662726441391898");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
 use algorithms::dijkstra;
@@ -10,7 +11,7 @@ pub fn part1(input: String) -> String {
     parser.reset().advance_to("E");
     let sink = parser.point();
 
-    let result = dijkstra(source, sink, &mut parser);
+    let result = dijkstra(source, sink, &parser);
 
     cut_costs_count(result.1, 2)
         .iter()
@@ -27,7 +28,7 @@ pub fn part2(input: String) -> String {
     parser.reset().advance_to("E");
     let sink = parser.point();
 
-    let result = dijkstra(source, sink, &mut parser);
+    let result = dijkstra(source, sink, &parser);
 
     cut_costs_count(result.1, 20)
         .iter()
@@ -70,10 +71,7 @@ fn with_gaps(a: &Point, b: &Point) -> usize {
     max.0 - min.0 + max.1 - min.1
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 ###############
 #...#...#.....#
 #.#.#.#.#.###.#
@@ -91,33 +89,6 @@ mod tests {
 ###############
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "0");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "0");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d20.txt").to_string()),
-            "1317"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d20.txt").to_string()),
-            "982474"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("0").part2("0")])
+    .answer(1, "1317")
+    .answer(2, "982474");
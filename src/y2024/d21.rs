@@ -1,9 +1,10 @@
+use crate::registry::{Day, Example};
 use parser::{Map, MultiLineParser};
 
 const NUMERIC_KEYPAD: &str = "
 789
 456
-123 
+123
  0A
 ";
 const DIRECTIONAL_KEYPAD: &str = "
@@ -22,52 +23,22 @@ pub fn part2(_input: String) -> String {
 
 #[allow(dead_code)]
 fn parse_input(input: String) -> (Map, Map, MultiLineParser) {
-    let mut numeric_keypad = MultiLineParser::new(NUMERIC_KEYPAD);
+    let mut numeric_keypad = MultiLineParser::new_preserving(NUMERIC_KEYPAD, ' ');
     let map: Map = (&mut numeric_keypad).into();
-    let mut directional_keypad = MultiLineParser::new(DIRECTIONAL_KEYPAD);
+    let mut directional_keypad = MultiLineParser::new_preserving(DIRECTIONAL_KEYPAD, ' ');
     let directional_map: Map = (&mut directional_keypad).into();
     let parser = MultiLineParser::new(&input);
     (map, directional_map, parser)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 029A
 980A
 179A
 456A
 379A
 ";
-    #[ignore]
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "126384");
-    }
-    #[ignore]
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "23");
-    }
-    #[ignore]
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d22.txt").to_string()),
-            "12664695565"
-        );
-    }
-    #[ignore]
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d22.txt").to_string()),
-            "1444"
-        );
-    }
-}
+
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("126384")])
+    .unsolved(1)
+    .unsolved(2);
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
 use parser::MultiLineParser;
@@ -62,9 +63,9 @@ impl Secret {
 
 fn parse_input(input: String) -> Vec<Secret> {
     MultiLineParser::new(&input)
-        .match_number()
+        .match_number::<u128>()
         .iter()
-        .map(|n| Secret(n.unwrap() as u128))
+        .map(|n| Secret(n.unwrap()))
         .collect()
 }
 
@@ -79,22 +80,31 @@ impl Iterator for Secret {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 1
 10
 100
 2024
 ";
 
-    const INPUT2: &str = "
+const INPUT2: &str = "
 1
 2
 3
 2024
 ";
+
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT).part1("37327623"),
+    Example::new(INPUT2).part2("23"),
+])
+.answer(1, "12664695565")
+.answer(2, "1444");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
     fn test_iteration() {
         let mut s = Secret(123);
@@ -109,34 +119,4 @@ mod tests {
         assert_eq!(s.next(), Some(7753432));
         assert_eq!(s.next(), Some(5908254));
     }
-
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "37327623");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT2.to_string());
-        assert_eq!(result, "23");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d22.txt").to_string()),
-            "12664695565"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d22.txt").to_string()),
-            "1444"
-        );
-    }
 }
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
@@ -100,10 +101,7 @@ fn parse_input(input: String) -> Vec<Vec<String>> {
     MultiLineParser::new(&input).split_to_strings("-")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 kh-tc
 qp-kh
 de-cg
@@ -138,33 +136,6 @@ tb-vc
 td-yn
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "7");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "co,de,ka,ta");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d23.txt").to_string()),
-            "1411"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d23.txt").to_string()),
-            "aq,bn,ch,dt,gu,ow,pk,qy,tv,us,yx,zg,zu"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("7").part2("co,de,ka,ta")])
+    .answer(1, "1411")
+    .answer(2, "aq,bn,ch,dt,gu,ow,pk,qy,tv,us,yx,zg,zu");
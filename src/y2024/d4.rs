@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::MultiLineParser;
 pub fn part1(input: String) -> String {
     let mut parser = parse_input(input);
@@ -26,10 +27,7 @@ fn parse_input(input: String) -> MultiLineParser {
     MultiLineParser::new(&input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "MMMSXXMASM
+const INPUT: &str = "MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -41,33 +39,6 @@ MAMMMXMMMM
 MXMXAXMASX
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "18");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "9");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d4.txt").to_string()),
-            "2401"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d4.txt").to_string()),
-            "1822"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("18").part2("9")])
+    .answer(1, "2401")
+    .answer(2, "1822");
@@ -1,6 +1,7 @@
+use crate::registry::{Day, Example};
 use std::cmp::Ordering;
 
-use parser::MultiLineParser;
+use parser::{MultiLineParser, Sections};
 
 pub fn part1(input: String) -> String {
     let (orders, pages) = parse_input(input);
@@ -26,9 +27,9 @@ pub fn part2(input: String) -> String {
 }
 
 fn parse_input(input: String) -> (Vec<Vec<i64>>, Vec<Vec<i64>>) {
-    let (orders_str, pages_str) = input.split_once("\n\n").unwrap();
-    let orders: Vec<Vec<i64>> = MultiLineParser::new(orders_str).split_to_numbers("|");
-    let pages: Vec<Vec<i64>> = MultiLineParser::new(pages_str).split_to_numbers(",");
+    let (orders, pages) = Sections::new(&input).pair::<MultiLineParser, MultiLineParser>();
+    let orders: Vec<Vec<i64>> = orders.split_to_numbers("|");
+    let pages: Vec<Vec<i64>> = pages.split_to_numbers(",");
     (orders, pages)
 }
 
@@ -51,10 +52,7 @@ fn sort_using_order(mut page: Vec<i64>, orders: &[Vec<i64>]) -> Vec<i64> {
     page
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "47|53
+const INPUT: &str = "47|53
 97|13
 97|61
 97|47
@@ -83,33 +81,6 @@ mod tests {
 61,13,29
 97,13,75,29,47";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "143");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "123");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d5.txt").to_string()),
-            "4872"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d5.txt").to_string()),
-            "5564"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("143").part2("123")])
+    .answer(1, "4872")
+    .answer(2, "5564");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -84,10 +85,7 @@ fn parse_input(input: String) -> MultiLineParser {
     MultiLineParser::new(&input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 ....#.....
 .........#
 ..........
@@ -99,33 +97,6 @@ mod tests {
 #.........
 ......#...";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "41");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "6");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d6.txt").to_string()),
-            "4515"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d6.txt").to_string()),
-            "1309"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("41").part2("6")])
+    .answer(1, "4515")
+    .answer(2, "1309");
@@ -1,4 +1,5 @@
-use parser::MultiLineParser;
+use crate::registry::{Day, Example};
+use parser::ByteParser;
 
 pub fn part1(input: String) -> String {
     let equations = parse_input(input);
@@ -23,22 +24,14 @@ pub fn part2(input: String) -> String {
 }
 
 fn parse_input(input: String) -> Vec<Vec<i64>> {
-    let mut parser = MultiLineParser::new(&input);
-    let mut equations: Vec<Vec<i64>> = parser
-        .match_number_up_to(':')
-        .iter()
-        .map(|num| vec![num.unwrap()])
-        .collect();
-
-    parser.advance_all_lines(1);
-
-    parser
-        .split_to_numbers(" ")
-        .iter()
-        .enumerate()
-        .for_each(|(i, nums)| nums.iter().for_each(|num| equations[i].push(*num)));
-
-    equations
+    ByteParser::lines(&input)
+        .map(|mut parser| {
+            let mut equation = vec![parser.match_number_up_to(b':').unwrap()];
+            parser.skip_whitespace();
+            equation.extend(parser.split_to_numbers(" "));
+            equation
+        })
+        .collect()
 }
 
 fn can_be_verified(nums: &[i64]) -> bool {
@@ -83,10 +76,7 @@ fn can_be_verified_part2(nums: &mut [i64]) -> bool {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 190: 10 19
 3267: 81 40 27
 83: 17 5
@@ -98,33 +88,6 @@ mod tests {
 292: 11 6 16 20
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "3749");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "11387");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d7.txt").to_string()),
-            "66343330034722"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d7.txt").to_string()),
-            "637696070419031"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("3749").part2("11387")])
+    .answer(1, "66343330034722")
+    .answer(2, "637696070419031");
@@ -1,4 +1,5 @@
-use parser::{Map, MultiLineParser};
+use crate::registry::{Day, Example};
+use parser::{Map, MultiLineParser, Point, PointI64};
 
 pub fn part1(input: String) -> String {
     let mut parser = parse_input(input);
@@ -23,15 +24,15 @@ fn parse_input(input: String) -> MultiLineParser {
 fn get_antinodes_from_map(map: Map, parser: MultiLineParser) -> MultiLineParser {
     let mut new_parser = parser.clone();
     for (_, points) in map.into_iter() {
-        for i in 0..points.len() {
-            for j in i + 1..points.len() {
-                if 2 * points[i].0 >= points[j].0 && 2 * points[i].1 >= points[j].1 {
-                    let antinode = (2 * points[i].0 - points[j].0, 2 * points[i].1 - points[j].1);
-                    new_parser.go_to(antinode).set(&'#');
-                }
-                if 2 * points[j].0 >= points[i].0 && 2 * points[j].1 >= points[i].1 {
-                    let antinode = (2 * points[j].0 - points[i].0, 2 * points[j].1 - points[i].1);
-                    new_parser.go_to(antinode).set(&'#');
+        for (i, &a) in points.iter().enumerate() {
+            for &b in &points[i + 1..] {
+                let step = difference(a, b);
+                let antinodes = [
+                    parser.ray(b, step).next(),
+                    parser.ray(a, (-step.0, -step.1)).next(),
+                ];
+                for (antinode, _) in antinodes.into_iter().flatten() {
+                    new_parser.set_at(antinode, '#');
                 }
             }
         }
@@ -42,32 +43,14 @@ fn get_antinodes_from_map(map: Map, parser: MultiLineParser) -> MultiLineParser
 fn get_antinodes_from_map_part2(map: Map, parser: MultiLineParser) -> MultiLineParser {
     let mut new_parser = parser.clone();
     for (_, points) in map.into_iter() {
-        for i in 0..points.len() {
-            for j in i + 1..points.len() {
-                let diff_line = points[j].0 - points[i].0;
-                let diff_cursor = points[i].1.max(points[j].1) - points[i].1.min(points[j].1);
-                let mut start = points[i];
-                if points[i].1 > points[j].1 {
-                    while start.0 >= diff_line && start.1 + diff_cursor < new_parser.cursor_len() {
-                        start.0 -= diff_line;
-                        start.1 += diff_cursor;
-                    }
-                    new_parser.go_to(start).set(&'#');
-                    while start.0 < new_parser.len() && start.1 >= diff_cursor {
-                        start.0 += diff_line;
-                        start.1 -= diff_cursor;
-                        new_parser.go_to(start).set(&'#');
-                    }
-                } else {
-                    while start.0 >= diff_line && start.1 >= diff_cursor {
-                        start.0 -= diff_line;
-                        start.1 -= diff_cursor;
-                    }
-                    while start.0 < new_parser.len() && start.1 < new_parser.cursor_len() {
-                        new_parser.go_to(start).set(&'#');
-                        start.0 += diff_line;
-                        start.1 += diff_cursor;
-                    }
+        for (i, &a) in points.iter().enumerate() {
+            for &b in &points[i + 1..] {
+                let step = difference(a, b);
+                new_parser.set_at(a, '#');
+                let forward = parser.ray(a, step);
+                let backward = parser.ray(a, (-step.0, -step.1));
+                for (antinode, _) in forward.chain(backward) {
+                    new_parser.set_at(antinode, '#');
                 }
             }
         }
@@ -75,10 +58,11 @@ fn get_antinodes_from_map_part2(map: Map, parser: MultiLineParser) -> MultiLineP
     new_parser
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+fn difference(from: Point, to: Point) -> PointI64 {
+    (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64)
+}
+
+const INPUT: &str = "
 ............
 ........0...
 .....0......
@@ -93,33 +77,6 @@ mod tests {
 ............
 ";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "14");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "34");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d8.txt").to_string()),
-            "220"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d8.txt").to_string()),
-            "813"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("14").part2("34")])
+    .answer(1, "220")
+    .answer(2, "813");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::Parser;
 
 pub fn part1(input: String) -> String {
@@ -131,38 +132,8 @@ fn parse_input2(input: String) -> Vec<DiskPart> {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "2333133121414131402";
+const INPUT: &str = "2333133121414131402";
 
-    #[test]
-    fn test_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "1928");
-    }
-
-    #[test]
-    fn test_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "2858");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2024/d9.txt").to_string()),
-            "6386640365805"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2024/d9.txt").to_string()),
-            "6423258376982"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("1928").part2("2858")])
+    .answer(1, "6386640365805")
+    .answer(2, "6423258376982");
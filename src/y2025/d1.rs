@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 enum Direction {
     Right(i32),
     Left(i32),
@@ -75,10 +76,7 @@ fn parse(input: &str) -> Vec<Direction> {
     lists
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "L68
+const INPUT: &str = "L68
 L30
 R48
 L5
@@ -89,33 +87,6 @@ L99
 R14
 L82";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "3");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "6");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2025/d1.txt").to_string()),
-            "1034"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2025/d1.txt").to_string()),
-            "6166"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("3").part2("6")])
+    .answer(1, "1034")
+    .answer(2, "6166");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::cmp::Ordering;
 use std::collections::{HashSet, VecDeque};
 use std::ops::Range;
@@ -236,48 +237,12 @@ fn toggle(pattern: &mut [char], place: &usize) {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
 [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
 [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "7");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "34");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "4781235324");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    #[cfg(feature = "test_slow")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "1566935900");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("7").part2("34")])
+    .slow(2)
+    .unsolved(2);
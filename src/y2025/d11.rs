@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
 use parser::MultiLineParser;
@@ -81,10 +82,7 @@ fn walk2(
     count
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 aaa: you hhh
 you: bbb ccc
 bbb: ddd eee
@@ -96,7 +94,8 @@ ggg: out
 hhh: ccc fff iii
 iii: out
 ";
-    const INPUT_2: &str = "
+
+const INPUT_2: &str = "
 svr: aaa bbb
 aaa: fft
 fft: ccc
@@ -112,39 +111,10 @@ ggg: out
 hhh: out
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "5");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT_2.to_string());
-        assert_eq!(result, "2");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "613");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    #[cfg(feature = "test_slow")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "372918445876116");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[
+    Example::new(INPUT).part1("5"),
+    Example::new(INPUT_2).part2("2"),
+])
+.answer(1, "613")
+.answer(2, "372918445876116")
+.slow(2);
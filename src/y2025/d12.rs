@@ -1,3 +1,6 @@
+use crate::registry::{Day, Example};
+use parser::Sections;
+
 #[derive(Clone, Debug)]
 struct Present {
     id: u32,
@@ -146,11 +149,7 @@ pub fn part2(_input: String) -> String {
 }
 
 fn parse_input(input: &str) -> (Vec<Present>, Vec<Region>) {
-    let mut parts = input
-        .split("\n\n")
-        .filter(|s| !s.is_empty())
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
+    let mut parts = Sections::new(input).all::<&str>();
     let regions = parts
         .pop()
         .unwrap()
@@ -216,10 +215,7 @@ fn walk(region: &mut Region, presents: &Vec<Present>) -> bool {
     false
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 0:
 ###
 ##.
@@ -255,26 +251,4 @@ mod tests {
 12x5: 1 0 1 0 3 2
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "1"); // 2 if we only return if res = ture in line 215
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "579");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("1")]).answer(1, "579");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::Parser;
 
 pub fn part1(input: String) -> String {
@@ -81,38 +82,8 @@ fn parse(input: &str) -> Vec<(u64, u64)> {
     ranges_num
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "1227775554");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "4174379265");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2025/d2.txt").to_string()),
-            "31210613313"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2025/d2.txt").to_string()),
-            "41823587546"
-        );
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("1227775554").part2("4174379265")])
+    .answer(1, "31210613313")
+    .answer(2, "41823587546");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::MultiLineParser;
 
 pub fn part1(input: String) -> String {
@@ -62,14 +63,19 @@ fn parse(input: &str) -> Vec<Vec<u64>> {
     nums
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "987654321111111
+const INPUT: &str = "987654321111111
 811111111111119
 234234234234278
 818181911112111";
 
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("357").part2("3121910778619")])
+    .answer(1, "17330")
+    .answer(2, "171518260283767");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
     fn test_parse() {
         let result = parse(INPUT);
@@ -79,34 +85,4 @@ mod tests {
             vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]
         );
     }
-
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "357");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "3121910778619");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(
-            part1(include_str!("../../.data/y2025/d3.txt").to_string()),
-            "17330"
-        );
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(
-            part2(include_str!("../../.data/y2025/d3.txt").to_string()),
-            "171518260283767"
-        );
-    }
 }
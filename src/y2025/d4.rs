@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::MultiLineParser;
 
 pub fn part1(input: String) -> String {
@@ -51,10 +52,7 @@ fn parse(input: &str) -> MultiLineParser {
     MultiLineParser::new(input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "..@@.@@@@.
+const INPUT: &str = "..@@.@@@@.
 @@@.@.@.@@
 @@@@@.@.@@
 @.@@@@..@.
@@ -65,38 +63,6 @@ mod tests {
 .@@@@@@@@.
 @.@.@@@.@.";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "13");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "43");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "1551");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "9784");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("13").part2("43")])
+    .answer(1, "1551")
+    .answer(2, "9784");
@@ -1,4 +1,5 @@
-use parser::MultiLineParser;
+use crate::registry::{Day, Example};
+use parser::{MultiLineParser, Sections};
 
 pub fn part1(input: String) -> String {
     let (ranges, ingredients) = parse_input(&input);
@@ -60,10 +61,9 @@ fn included_ranges(ranges: &[(i64, i64)], num: i64) -> Vec<(i64, i64)> {
 }
 
 fn parse_input(input: &str) -> (Vec<Vec<i64>>, Vec<i64>) {
-    let (ranges_str, ingredients_str) = input.split_once("\n\n").unwrap();
-    let ranges = MultiLineParser::new(ranges_str);
-    let ingredients = MultiLineParser::new(ingredients_str)
-        .match_number()
+    let (ranges, mut ingredients) = Sections::new(input).pair::<MultiLineParser, MultiLineParser>();
+    let ingredients = ingredients
+        .match_number::<i64>()
         .iter()
         .map(|v| v.unwrap())
         .collect();
@@ -72,10 +72,7 @@ fn parse_input(input: &str) -> (Vec<Vec<i64>>, Vec<i64>) {
     (ranges_vec, ingredients)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "3-5
+const INPUT: &str = "3-5
 10-14
 16-20
 12-18
@@ -87,38 +84,6 @@ mod tests {
 17
 32";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "3");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "14");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "563");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "338693411431456");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("3").part2("14")])
+    .answer(1, "563")
+    .answer(2, "338693411431456");
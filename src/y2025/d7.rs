@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::collections::HashMap;
 
 use parser::{Direction, MultiLineParser, Point};
@@ -78,13 +79,25 @@ fn walk_part2(parser: &mut MultiLineParser, memo: &mut HashMap<Point, usize>) ->
     let mut count = 0;
 
     if next == &'^' {
-        parser.advance_with_direction(1, &Direction::DownLeft);
-        count += walk_part2(parser, memo);
-        parser.advance_with_direction(1, &Direction::UpRight);
+        if parser
+            .try_advance_with_direction(1, &Direction::DownLeft)
+            .is_some()
+        {
+            count += walk_part2(parser, memo);
+            parser.advance_with_direction(1, &Direction::UpRight);
+        } else {
+            count += 1;
+        }
 
-        parser.advance_with_direction(1, &Direction::RightDown);
-        count += walk_part2(parser, memo);
-        parser.advance_with_direction(1, &Direction::LeftUp);
+        if parser
+            .try_advance_with_direction(1, &Direction::RightDown)
+            .is_some()
+        {
+            count += walk_part2(parser, memo);
+            parser.advance_with_direction(1, &Direction::LeftUp);
+        } else {
+            count += 1;
+        }
     } else {
         parser.advance_with_direction(1, &Direction::Down);
         count += walk_part2(parser, memo);
@@ -98,10 +111,7 @@ fn parse_input(input: &str) -> MultiLineParser {
     MultiLineParser::new(input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 .......S.......
 ...............
 .......^.......
@@ -120,38 +130,6 @@ mod tests {
 ...............
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "21");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "40");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "1613");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "48021610271997");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("21").part2("40")])
+    .answer(1, "1613")
+    .answer(2, "48021610271997");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use std::{cmp::Ordering, collections::HashMap};
 
 use parser::MultiLineParser;
@@ -19,9 +20,17 @@ impl Distance for Point {
 }
 
 pub fn part1(input: String) -> String {
+    largest_circuits(input, 1000)
+}
+
+fn example_part1(input: String) -> String {
+    largest_circuits(input, 10)
+}
+
+fn largest_circuits(input: String, connections: usize) -> String {
     let mut numbers = parse_input(&input);
 
-    let result = merge_numbers(&mut numbers, |_ved, conn, _a, _b| conn == 1000);
+    let result = merge_numbers(&mut numbers, |_ved, conn, _a, _b| conn == connections);
     result.to_string()
 }
 
@@ -111,10 +120,7 @@ fn merge(array: &mut Vec<Vec<Point>>, a: &Point, b: &Point) {
     array.remove(position_b);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 162,817,812
 57,618,57
 906,360,560
@@ -137,40 +143,9 @@ mod tests {
 425,690,689
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let mut numbers = parse_input(INPUT);
-
-        let result = merge_numbers(&mut numbers, |_ved, conn, _a, _b| conn == 10);
-        assert_eq!(result, 40);
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "25272");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "102816");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "100011612");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT)
+    .part1("40")
+    .part2("25272")
+    .solver(1, example_part1)])
+.answer(1, "102816")
+.answer(2, "100011612");
@@ -1,3 +1,4 @@
+use crate::registry::{Day, Example};
 use parser::{MultiLineParser, Point, PointI64};
 pub fn part1(input: String) -> String {
     let numbers = parse_input(&input);
@@ -107,10 +108,7 @@ fn parse_input_2(input: &str) -> Vec<Point> {
         .collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "
+const INPUT: &str = "
 7,1
 11,1
 11,7
@@ -121,39 +119,7 @@ mod tests {
 7,3
 ";
 
-    #[test]
-    fn test_example_part1() {
-        let result = part1(INPUT.to_string());
-        assert_eq!(result, "50");
-    }
-
-    #[test]
-    fn test_example_part2() {
-        let result = part2(INPUT.to_string());
-        assert_eq!(result, "24");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    fn test_part1_input() {
-        assert_eq!(part1(input_file()), "4781235324");
-    }
-
-    #[test]
-    #[cfg(feature = "test_input")]
-    #[cfg(feature = "test_slow")]
-    fn test_part2_input() {
-        assert_eq!(part2(input_file()), "1566935900");
-    }
-
-    #[cfg(feature = "test_input")]
-    fn input_file() -> String {
-        let name = file!();
-        let basename = std::path::Path::new(name)
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".rs", "");
-        std::fs::read_to_string(format!(".data/y2025/{}.txt", basename)).unwrap()
-    }
-}
+pub const DAY: Day = Day::new(&[Example::new(INPUT).part1("50").part2("24")])
+    .answer(1, "4781235324")
+    .answer(2, "1566935900")
+    .slow(2);
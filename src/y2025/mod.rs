@@ -1,51 +1,37 @@
 //! Auto-generated file by build script, do not edit!
-pub mod d10;
-pub mod d11;
-pub mod d4;
-pub mod d1;
 pub mod d5;
-pub mod d2;
-pub mod d6;
 pub mod d7;
+pub mod d9;
+pub mod d2;
+pub mod d4;
 pub mod d3;
+pub mod d11;
 pub mod d8;
+pub mod d6;
 pub mod d12;
-pub mod d9;
+pub mod d1;
+pub mod d10;
 /// Selects the function for the given day and part
 pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, String> {
     match day {
-        10 => {
-            match part {
-                1 => Ok(d10::part1),
-                2 => Ok(d10::part2),
-                _ => Err("Invalid part!".into()),
-            }
-        }
-        11 => {
-            match part {
-                1 => Ok(d11::part1),
-                2 => Ok(d11::part2),
-                _ => Err("Invalid part!".into()),
-            }
-        }
-        4 => {
+        5 => {
             match part {
-                1 => Ok(d4::part1),
-                2 => Ok(d4::part2),
+                1 => Ok(d5::part1),
+                2 => Ok(d5::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
-        1 => {
+        7 => {
             match part {
-                1 => Ok(d1::part1),
-                2 => Ok(d1::part2),
+                1 => Ok(d7::part1),
+                2 => Ok(d7::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
-        5 => {
+        9 => {
             match part {
-                1 => Ok(d5::part1),
-                2 => Ok(d5::part2),
+                1 => Ok(d9::part1),
+                2 => Ok(d9::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
@@ -56,24 +42,24 @@ pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, Stri
                 _ => Err("Invalid part!".into()),
             }
         }
-        6 => {
+        4 => {
             match part {
-                1 => Ok(d6::part1),
-                2 => Ok(d6::part2),
+                1 => Ok(d4::part1),
+                2 => Ok(d4::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
-        7 => {
+        3 => {
             match part {
-                1 => Ok(d7::part1),
-                2 => Ok(d7::part2),
+                1 => Ok(d3::part1),
+                2 => Ok(d3::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
-        3 => {
+        11 => {
             match part {
-                1 => Ok(d3::part1),
-                2 => Ok(d3::part2),
+                1 => Ok(d11::part1),
+                2 => Ok(d11::part2),
                 _ => Err("Invalid part!".into()),
             }
         }
@@ -84,6 +70,13 @@ pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, Stri
                 _ => Err("Invalid part!".into()),
             }
         }
+        6 => {
+            match part {
+                1 => Ok(d6::part1),
+                2 => Ok(d6::part2),
+                _ => Err("Invalid part!".into()),
+            }
+        }
         12 => {
             match part {
                 1 => Ok(d12::part1),
@@ -91,10 +84,17 @@ pub fn select_function(day: u32, part: u32) -> Result<fn(String) -> String, Stri
                 _ => Err("Invalid part!".into()),
             }
         }
-        9 => {
+        1 => {
             match part {
-                1 => Ok(d9::part1),
-                2 => Ok(d9::part2),
+                1 => Ok(d1::part1),
+                2 => Ok(d1::part2),
+                _ => Err("Invalid part!".into()),
+            }
+        }
+        10 => {
+            match part {
+                1 => Ok(d10::part1),
+                2 => Ok(d10::part2),
                 _ => Err("Invalid part!".into()),
             }
         }